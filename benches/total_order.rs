@@ -0,0 +1,55 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use dec754::d32;
+
+fn mix() -> Vec<d32> {
+    // mostly finite values over the whole exponent range,
+    // with zeros, infinities and nans sprinkled through;
+    // xorshift keeps it the same from run to run
+    let mut state = 0x2545f491u32;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        state
+    };
+    let specials = ["0", "-0E+5", "Inf", "-Inf", "NaN12", "-sNaN3", "1.000", "1"];
+    (0..1024)
+        .map(|i| {
+            if i % 16 == 0 {
+                specials[i / 16 % specials.len()].parse().unwrap()
+            } else {
+                let x = d32::from_bits(next());
+                if x.is_finite() {
+                    x.canonicalize()
+                } else {
+                    d32::from_bits(next() & 0x77ffffff)
+                }
+            }
+        })
+        .collect()
+}
+
+fn total_order(c: &mut Criterion) {
+    let xs = mix();
+    c.bench_function("total_order", |b| {
+        b.iter(|| {
+            let mut count = 0u32;
+            for pair in xs.windows(2) {
+                count += black_box(pair[0]).total_order(&black_box(pair[1])) as u32;
+            }
+            count
+        })
+    });
+    c.bench_function("total_order_mag", |b| {
+        b.iter(|| {
+            let mut count = 0u32;
+            for pair in xs.windows(2) {
+                count += black_box(pair[0]).total_order_mag(&black_box(pair[1])) as u32;
+            }
+            count
+        })
+    });
+}
+
+criterion_group!(benches, total_order);
+criterion_main!(benches);
@@ -10,10 +10,36 @@
 // x xxxxxxxxxxx xxxxxxxxxxxxxxxxxxxx
 // 1 11122223333 44445555666677778888
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String, vec::Vec};
+use core::convert::TryFrom;
+use core::fmt::Write;
+
+const PRECISION: u32 = 7;
+const BIAS: i32 = 101;
+const EMAX: i32 = 96;
+const EMIN: i32 = -95;
+// exponents as seen by the coefficient (i.e. of its last digit)
+const QMAX: i32 = EMAX - PRECISION as i32 + 1;
+const QMIN: i32 = EMIN - PRECISION as i32 + 1;
+const MAX_COEFFICIENT: u64 = 9999999;
+
+/// A decimal32 in its DPD encoding. It's `repr(transparent)`
+/// over that `u32`, so `size_of::<d32>() == 4` with the same
+/// alignment, `&[d32]` and `&[u32]` can be cast either way, and
+/// it passes across a C ABI as a `_Decimal32` would. Every bit
+/// pattern is some d32, noncanonical or not.
 #[derive(Clone, Copy, Debug)]
 #[allow(non_camel_case_types)]
+#[repr(transparent)]
 pub struct d32(u32);
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Class {
     QuietNaN,
     SignalingNaN,
@@ -27,7 +53,140 @@ pub enum Class {
     PositiveInf,
 }
 
+// decimal64: p = 16, emax = 384, emin = -383, bias = 398,
+// with a 13-bit combination field and five declets
+#[derive(Clone, Copy, Debug)]
+#[allow(non_camel_case_types)]
+pub struct d64(u64);
+
+#[derive(Clone, Copy, Debug)]
+pub struct TotalOrd(pub d32);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoundingMode {
+    TiesToEven,
+    TiesToAway,
+    TowardZero,
+    TowardPositive,
+    TowardNegative,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Flags {
+    pub invalid: bool,
+    pub div_by_zero: bool,
+    pub overflow: bool,
+    pub underflow: bool,
+    pub inexact: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Context {
+    pub rounding: RoundingMode,
+    pub flags: Flags,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Comparison {
+    Less,
+    Equal,
+    Greater,
+    Unordered,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConversionError {
+    NotInteger,
+    OutOfRange,
+    Infinite,
+    NaN,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BuildError {
+    CoefficientTooLarge,
+    ExponentOutOfRange,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Special {
+    Infinity,
+    QuietNaN { payload: u32 },
+    SignalingNaN { payload: u32 },
+}
+
+// a value taken apart for formatting: the coefficient's digits
+// (0 through 9, not ascii) with no leading zeros but at least
+// one digit, and its exponent; specials have neither
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecimalParts {
+    pub sign: bool,
+    pub coefficient_digits: Vec<u8>,
+    pub exponent: i32,
+    pub special: Option<Special>,
+}
+
+// the same without an allocator: the first `len` of `digits`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FixedDecimalParts {
+    pub sign: bool,
+    pub digits: [u8; 7],
+    pub len: usize,
+    pub exponent: i32,
+    pub special: Option<Special>,
+}
+
+impl FixedDecimalParts {
+    pub fn coefficient_digits(&self) -> &[u8] {
+        &self.digits[..self.len]
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseDecimalError {
+    Empty,
+    InvalidDigit,
+    InvalidExponent,
+    PayloadTooLarge,
+    InvalidGrouping,
+}
+
+// what `parse_with_options` accepts beyond plain `FromStr`; the
+// default is exactly `FromStr`, with no grouping and a '.' point
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseOptions {
+    pub thousands_separator: Option<char>,
+    pub decimal_point: char,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions {
+            thousands_separator: None,
+            decimal_point: '.',
+        }
+    }
+}
+
 impl d32 {
+    /* ********************************************** *
+     *                   CONSTANTS                    *
+     * ********************************************** */
+
+    pub const ZERO: d32 = d32(0x22500000);
+    pub const NEG_ZERO: d32 = d32(0xa2500000);
+    pub const ONE: d32 = d32(0x22500001);
+    pub const INFINITY: d32 = d32(0x78000000);
+    pub const NEG_INFINITY: d32 = d32(0xf8000000);
+    pub const NAN: d32 = d32(0x7c000000);
+    // 9.999999E+96
+    pub const MAX: d32 = d32(0x77f3fcff);
+    // 1E-95, the smallest normal
+    pub const MIN_POSITIVE: d32 = d32(0x00600001);
+    // 1E-101, the smallest subnormal
+    pub const MIN_POSITIVE_SUBNORMAL: d32 = d32(0x00000001);
+
     /* ********************************************** *
      *                HELPER FUNCTIONS                *
      * ********************************************** */
@@ -39,26 +198,30 @@ impl d32 {
     }
 
     fn significand(&self) -> u64 {
-        // if exponent is of first form, then signif
-        // is last 23 bits; otherwise, it's last 21
-        // bits, with 100 leading for a total of 24
-        if self.exponent_form_one() {
-            (self.0 & 0x007fffff) as u64
+        // the leading digit comes out of the combination
+        // field: G2 through G4 in the first form, or 100
+        // followed by G4 in the second; the other six
+        // digits are the two declets of the trailing field
+        let lead = if self.exponent_form_one() {
+            (self.0 >> 26) & 0x7
         } else {
-            ((self.0 & 0x001fffff) | 0x00800000) as u64
-        }
+            8 | ((self.0 >> 26) & 0x1)
+        };
+        let hi = decode_declet(((self.0 >> 10) & 0x3ff) as u16);
+        let lo = decode_declet((self.0 & 0x3ff) as u16);
+        lead as u64 * 1000000 + hi as u64 * 1000 + lo as u64
     }
 
     fn exponent(&self) -> u32 {
-        // if exponent is of first form, then it is
-        // G0 through G7; otherwise, it's G2 through
-        // G9 (inclusive both times)
+        // the top two exponent bits are G0 and G1 in the
+        // first form and G2 and G3 in the second; either
+        // way, the rest are G5 through G10
         if !self.is_finite() {
             0
         } else if self.exponent_form_one() {
-            (self.0 & 0x7f800000) >> 23
+            ((self.0 >> 23) & 0xc0) | ((self.0 >> 20) & 0x3f)
         } else {
-            (self.0 & 0x1fe00000) >> 21
+            ((self.0 >> 21) & 0xc0) | ((self.0 >> 20) & 0x3f)
         }
     }
 
@@ -66,253 +229,8205 @@ impl d32 {
         self.is_nan() && !self.is_signaling()
     }
 
-    /* ********************************************** *
-     *             GENERAL-COMP FUNCTIONS             *
-     * ********************************************** */
+    fn decode(&self) -> (bool, u64, i32) {
+        (
+            self.is_sign_minus(),
+            self.significand(),
+            self.exponent() as i32 - BIAS,
+        )
+    }
 
-    pub fn quantum(&self) -> Self {
-        // if it's a NaN, we want to canonicalize it
-        // and propagate its payload
-        if self.is_signaling() {
-            d32(self.0 & 0x7e07ffff)
-        } else if self.is_nan() {
-            d32(self.0 & 0x7c07ffff)
-        // if it's +/-inf, its quantum is +inf
-        } else if self.is_infinite() {
+    const fn encode(sign: bool, coefficient: u64, exponent: i32) -> Self {
+        // caller guarantees coefficient <= 9999999 and
+        // that exponent lies within qmin..=qmax
+        let sign = if sign { 0x80000000 } else { 0 };
+        let biased = (exponent + BIAS) as u32;
+        let lead = (coefficient / 1000000) as u32;
+        // leading digits of 8 and 9 need the second form,
+        // which moves the top exponent bits over by two
+        let combination = if lead < 8 {
+            (biased & 0xc0) << 23 | lead << 26
+        } else {
+            0x60000000 | (biased & 0xc0) << 21 | (lead & 0x1) << 26
+        };
+        let hi = encode_declet((coefficient / 1000 % 1000) as u16) as u32;
+        let lo = encode_declet((coefficient % 1000) as u16) as u32;
+        d32(sign | combination | (biased & 0x3f) << 20 | hi << 10 | lo)
+    }
+
+    fn quieted(&self) -> Self {
+        // keep the sign and payload, clear the signaling
+        // bit and anything else in the combination field
+        d32((self.0 & 0x800fffff) | 0x7c000000)
+    }
+
+    fn propagate_nan(&self, y: &d32) -> (Self, Flags) {
+        // signaling nans take precedence over quiet ones,
+        // and the first operand over the second; any
+        // signaling nan at all makes it invalid
+        let flags = Flags {
+            invalid: self.is_signaling() || y.is_signaling(),
+            ..Flags::default()
+        };
+        if self.is_signaling() || (!y.is_signaling() && self.is_nan()) {
+            (self.quieted(), flags)
+        } else {
+            (y.quieted(), flags)
+        }
+    }
+
+    fn nan_with_payload(sign: bool, signaling: bool, payload: u32) -> Self {
+        // caller guarantees the payload is at most 6 digits,
+        // which go in the trailing declets just like finites
+        let sign = if sign { 0x80000000 } else { 0 };
+        let nan = if signaling { 0x7e000000 } else { 0x7c000000 };
+        let hi = encode_declet((payload / 1000) as u16) as u32;
+        let lo = encode_declet((payload % 1000) as u16) as u32;
+        d32(sign | nan | hi << 10 | lo)
+    }
+
+    fn nan_payload(&self) -> u32 {
+        let hi = decode_declet(((self.0 >> 10) & 0x3ff) as u16);
+        let lo = decode_declet((self.0 & 0x3ff) as u16);
+        hi as u32 * 1000 + lo as u32
+    }
+
+    fn number_over_nan(&self, y: &d32) -> (Self, Flags) {
+        // for exactly one nan, give back the other operand
+        let flags = Flags {
+            invalid: self.is_signaling() || y.is_signaling(),
+            ..Flags::default()
+        };
+        (if self.is_nan() { *y } else { *self }, flags)
+    }
+
+    fn invalid() -> (Self, Flags) {
+        let flags = Flags {
+            invalid: true,
+            ..Flags::default()
+        };
+        (d32(0x7c000000), flags)
+    }
+
+    fn infinity(sign: bool) -> Self {
+        if sign {
+            d32(0xf8000000)
+        } else {
             d32(0x78000000)
-        // for finite numbers, we clear the sign bit,
-        // leave the exponent untouched, and wipe out
-        // the significand, leaving 1
-        } else if self.exponent_form_one() {
-            d32(self.0 & 0x7f800001)
+        }
+    }
+
+    fn overflow(sign: bool, mode: RoundingMode) -> (Self, Flags) {
+        // round-to-nearest always overflows to infinity;
+        // directed modes stop at the largest finite number
+        // when infinity lies in the wrong direction
+        let to_infinity = match mode {
+            RoundingMode::TiesToEven | RoundingMode::TiesToAway => true,
+            RoundingMode::TowardZero => false,
+            RoundingMode::TowardPositive => !sign,
+            RoundingMode::TowardNegative => sign,
+        };
+        let flags = Flags {
+            overflow: true,
+            inexact: true,
+            ..Flags::default()
+        };
+        if to_infinity {
+            (d32::infinity(sign), flags)
         } else {
-            d32(self.0 & 0x7fe00001)
+            (d32::encode(sign, MAX_COEFFICIENT, QMAX), flags)
         }
     }
 
-    /* ********************************************** *
-     *              QUIET-COMP FUNCTIONS              *
-     * ********************************************** */
+    fn finalize(sign: bool, coefficient: u128, exponent: i32, mode: RoundingMode) -> (Self, Flags) {
+        // every rounded result comes through here; the exact
+        // value is (-1)^sign * coefficient * 10^exponent. drop
+        // enough trailing digits to fit the precision, and
+        // more if needed to bring the exponent up to qmin
+        // (which is all there is to rounding subnormals)
+        let digits = digit_count(coefficient);
+        let mut drop = (digits as i32 - PRECISION as i32).max(0);
+        if exponent + drop < QMIN {
+            drop = QMIN - exponent;
+        }
+        let mut exponent = exponent + drop;
+        let (mut q, inexact) = round_off(coefficient, drop as u32, sign, mode);
+        // tiny means nonzero and below the normal range, judged
+        // on the exact value: decimal formats detect tininess
+        // before rounding, so something that rounds up to the
+        // smallest normal still underflows (if inexact)
+        let tiny = coefficient != 0 && exponent - drop + digits as i32 - 1 < EMIN;
+        let flags = Flags {
+            underflow: tiny && inexact,
+            inexact,
+            ..Flags::default()
+        };
+        if q > MAX_COEFFICIENT {
+            q /= 10;
+            exponent += 1;
+        }
+        if q != 0 && exponent + digit_count(q as u128) as i32 - 1 > EMAX {
+            return d32::overflow(sign, mode);
+        }
+        // exponents above qmax are fine so long as we can
+        // pad the coefficient with zeros to bring them down
+        // (which for zero is always)
+        if exponent > QMAX {
+            q *= pow10((exponent - QMAX).min(PRECISION as i32) as u32) as u64;
+            exponent = QMAX;
+        }
+        (d32::encode(sign, q, exponent), flags)
+    }
 
-    pub fn negate(&self) -> Self {
-        d32(self.0 ^ 0x80000000)
+    fn round_digits<I: IntoIterator<Item = u8>>(
+        sign: bool,
+        digits: I,
+        exponent: i64,
+        mode: RoundingMode,
+    ) -> (Self, Flags) {
+        // like finalize, but for big-endian digits of any
+        // length; past what a u128 holds, the rest only
+        // matter as a sticky digit
+        let mut coefficient = 0u128;
+        let mut kept = 0;
+        let mut dropped = 0i64;
+        let mut sticky = false;
+        for d in digits {
+            if kept < 34 {
+                coefficient = coefficient * 10 + d as u128;
+                if coefficient != 0 {
+                    kept += 1;
+                }
+            } else {
+                sticky |= d != 0;
+                dropped += 1;
+            }
+        }
+        let mut exponent = exponent + dropped;
+        if sticky {
+            coefficient = coefficient * 10 + 1;
+            exponent -= 1;
+        }
+        // anything this far out is going to overflow or
+        // underflow all the same
+        let exponent = exponent.clamp(-1_000_000_000, 1_000_000_000) as i32;
+        d32::finalize(sign, coefficient, exponent, mode)
     }
 
-    pub fn abs(&self) -> Self {
-        d32(self.0 & 0x7fffffff)
+    fn round_approx(
+        sign: bool,
+        coefficient: u128,
+        exponent: i32,
+        error: u128,
+        mode: RoundingMode,
+    ) -> (Self, Flags) {
+        // rounds a coefficient known only to within `error`
+        // units, and so never exact: if both ends of the
+        // interval round the same way then so does whatever
+        // lies between; otherwise a directed mode takes the end
+        // that can't be on the wrong side of the true value,
+        // and the nearest modes the best guess, still within
+        // an ulp either way
+        let low = d32::finalize(sign, (coefficient - error) * 10 + 1, exponent - 1, mode);
+        let high = d32::finalize(sign, (coefficient + error) * 10 - 1, exponent - 1, mode);
+        if low.0.to_bits() == high.0.to_bits() {
+            return low;
+        }
+        match mode {
+            RoundingMode::TowardZero => low,
+            RoundingMode::TowardPositive => {
+                if sign {
+                    low
+                } else {
+                    high
+                }
+            }
+            RoundingMode::TowardNegative => {
+                if sign {
+                    high
+                } else {
+                    low
+                }
+            }
+            _ => d32::finalize(sign, coefficient * 10 + 1, exponent - 1, mode),
+        }
     }
 
-    pub fn copy_sign(&self, y: &d32) -> Self {
-        d32(self.abs().0 | (y.0 & 0x80000000))
+    fn log_special(&self) -> Option<(Self, Flags)> {
+        // what ln and log10 have in common: nans, negatives,
+        // zeros and infinity, and exactly one going to +0
+        if self.is_nan() {
+            return Some(self.propagate_nan(self));
+        }
+        if self.is_zero() {
+            let flags = Flags {
+                div_by_zero: true,
+                ..Flags::default()
+            };
+            return Some((d32::NEG_INFINITY, flags));
+        }
+        if self.is_sign_minus() {
+            return Some(d32::invalid());
+        }
+        if self.is_infinite() {
+            return Some((d32::INFINITY, Flags::default()));
+        }
+        if *self == d32::ONE {
+            return Some((d32::ZERO, Flags::default()));
+        }
+        None
     }
 
-    pub fn encode_binary(&self) -> Self {
-        d32(self.0)
+    fn integer_parity(&self) -> Option<bool> {
+        // whether a finite integer is odd, or none for anything
+        // else; past the precision everything's a multiple of ten
+        if !self.is_finite() {
+            return None;
+        }
+        let (_, mut c, mut e) = self.decode();
+        while c != 0 && c % 10 == 0 && e < 0 {
+            c /= 10;
+            e += 1;
+        }
+        if c != 0 && e < 0 {
+            return None;
+        }
+        Some(c != 0 && e == 0 && c % 2 == 1)
     }
 
-    pub fn decode_binary(&self) -> Self {
-        d32(self.0)
+    fn power(c: u128, e: i32, n: i32, sign: bool, mode: RoundingMode) -> (Self, Flags) {
+        // (-1)^sign (c 10^e)^n for nonzero c and n; the zeros
+        // come off the coefficient first so they can't make an
+        // exact power look too big (or inexact), and go back on
+        // afterward as far as the ideal exponent e n wants
+        let power = n.unsigned_abs();
+        let (mut c, mut zeros) = (c, 0i64);
+        while c.is_multiple_of(10) {
+            c /= 10;
+            zeros += 1;
+        }
+        let exponent = |shift: i64| {
+            ((e as i64 + zeros) * power as i64 + shift).clamp(-1_000_000_000, 1_000_000_000) as i32
+        };
+        if n > 0 {
+            if let Some(mut p) = c.checked_pow(power) {
+                let mut padding = zeros * power as i64;
+                while padding > 0 && digit_count(p) < PRECISION {
+                    p *= 10;
+                    padding -= 1;
+                }
+                let shift = zeros * power as i64 - padding;
+                return d32::finalize(sign, p, exponent(-shift), mode);
+            }
+        } else if let Some((q, places)) = terminating_reciprocal(c, power) {
+            // 1 / c^n terminates just when c is a power of two
+            // or of five, and then it's exact
+            return d32::finalize(sign, q, -exponent(places as i64), mode);
+        } else if let Some(p) = c.checked_pow(power).filter(|&p| p <= pow10(29)) {
+            // otherwise it never ends, and nine digits or more
+            // with a sticky one below them will round correctly
+            let q = pow10(38) / p;
+            return d32::finalize(sign, q * 10 + 1, -exponent(39), mode);
+        }
+        let e = (e as i64 + zeros).clamp(-1_000_000_000, 1_000_000_000) as i32;
+        d32::pow_approx(c, e, (n < 0, power as u64, 0), sign, mode)
     }
 
-    /* ********************************************** *
-     *               NON-COMP FUNCTIONS               *
-     * ********************************************** */
+    fn exact_root(c: u64, e: i32, n: u32) -> Option<(u128, i32)> {
+        // the nth root of c 10^e if it's a decimal, found by
+        // moving zeros between the coefficient and exponent
+        // until the exponent is a multiple of n
+        let ideal = e.div_euclid(n as i32);
+        let (mut c, mut e) = (c as u128, e);
+        while c.is_multiple_of(10) {
+            c /= 10;
+            e += 1;
+        }
+        let shift = e.rem_euclid(n as i32) as u32;
+        let c = c.checked_mul(10u128.checked_pow(shift)?)?;
+        let mut root = iroot(c, n);
+        if root.pow(n) != c {
+            return None;
+        }
+        // then zeros back on, toward the ideal exponent e/n
+        let mut exponent = (e - shift as i32) / n as i32;
+        while exponent > ideal && root * 10 <= MAX_COEFFICIENT as u128 {
+            root *= 10;
+            exponent -= 1;
+        }
+        Some((root, exponent))
+    }
+
+    fn fractional_root(
+        c: u64,
+        e: i32,
+        y_coefficient: u64,
+        y_exponent: i32,
+    ) -> Option<(u128, i32, i32)> {
+        // for y = m / 10^k, the exact root of degree 10^k (less
+        // whatever it has in common with m) along with the m
+        // it's to be raised to, if there is one
+        let (mut m, mut k) = (y_coefficient, y_exponent);
+        while m.is_multiple_of(10) {
+            m /= 10;
+            k += 1;
+        }
+        if !(-9..0).contains(&k) {
+            return None;
+        }
+        let mut degree = 10u32.pow(k.unsigned_abs());
+        for factor in [2, 5] {
+            while m.is_multiple_of(factor) && degree.is_multiple_of(factor as u32) {
+                m /= factor;
+                degree /= factor as u32;
+            }
+        }
+        let (root, exponent) = d32::exact_root(c, e, degree)?;
+        Some((root, exponent, m as i32))
+    }
 
-    pub fn is_754_version_1985() -> bool {
-        todo!()
+    fn exp_approx(t: i128, error: u128, sign: bool, mode: RoundingMode) -> (Self, Flags) {
+        // rounds (-1)^sign exp(t) for a fixed point t that's
+        // known to within `error`, where the true t isn't zero;
+        // within 1e-20 of zero, the result is just to one side
+        // of one, and t need only have the right sign
+        if t.unsigned_abs() < pow10(12) {
+            let nudged = if t < 0 { pow10(34) - 1 } else { pow10(34) + 1 };
+            return d32::finalize(sign, nudged, -34, mode);
+        }
+        let (m, k) = fixed_exp(t);
+        d32::round_approx(sign, m, k - 32, error, mode)
     }
 
-    pub fn is_754_version_2008() -> bool {
-        todo!()
+    fn pow_approx(
+        c: u128,
+        e: i32,
+        (y_sign, y_coefficient, y_exponent): (bool, u64, i32),
+        sign: bool,
+        mode: RoundingMode,
+    ) -> (Self, Flags) {
+        // |x|^y as exp(y ln |x|), for a finite nonzero x and
+        // finite nonzero y; ln |x| is zero only when |x| is one
+        let ln = fixed_ln(c, e);
+        if ln == 0 {
+            return (d32::encode(sign, 1, 0), Flags::default());
+        }
+        let negative = (ln < 0) != y_sign;
+        // the adjusted exponents of both factors say whether
+        // the product is past ten thousand, far out of range
+        let ln_adjusted = digit_count(ln.unsigned_abs()) as i32 - 33;
+        let y_adjusted = y_exponent + digit_count(y_coefficient as u128) as i32 - 1;
+        if ln_adjusted + y_adjusted >= 4 {
+            if negative {
+                return d32::finalize(sign, 1, -1000, mode);
+            }
+            return d32::overflow(sign, mode);
+        }
+        let t = fixed_mul_decimal(ln.unsigned_abs(), y_coefficient, y_exponent) as i128;
+        // the error in ln |x| gets multiplied by y
+        let y_bound = if y_adjusted < 0 {
+            1
+        } else {
+            y_coefficient as u128 * pow10(y_exponent.max(0) as u32) + 1
+        };
+        // a tiny y can leave t truncated to zero, but exp_approx
+        // still needs it on the right side
+        let t = t.max(1);
+        let t = if negative { -t } else { t };
+        d32::exp_approx(t, FIXED_ERROR * 10 * y_bound, sign, mode)
     }
 
-    pub fn is_754_version_2019() -> bool {
-        todo!()
+    fn divide_truncated(&self, y: &d32) -> Option<(u128, u128, i32)> {
+        // for finite x and nonzero finite y, the integer part
+        // of |x / y| and what's left of |x| after taking that
+        // many |y|, at the smaller exponent; none if the
+        // quotient needs more than the precision
+        let (_, cx, ex) = self.decode();
+        let (_, cy, ey) = y.decode();
+        let (cx, cy) = (cx as u128, cy as u128);
+        let (q, r) = if ex >= ey {
+            if cx != 0 && ex - ey > 14 {
+                return None;
+            }
+            let x = cx * pow10((ex - ey).min(14) as u32);
+            (x / cy, x % cy)
+        } else if ey - ex <= 20 {
+            let y = cy * pow10((ey - ex) as u32);
+            (cx / y, cx % y)
+        } else {
+            (0, cx)
+        };
+        if q > MAX_COEFFICIENT as u128 {
+            return None;
+        }
+        Some((q, r, ex.min(ey)))
     }
 
-    pub fn class(&self) -> Class {
-        if self.is_signaling() {
-            Class::SignalingNaN
-        } else if self.is_quiet() {
-            Class::QuietNaN
-        } else if self.is_sign_minus() {
-            if self.is_infinite() {
-                Class::NegativeInf
-            } else if self.is_normal() {
-                Class::NegativeNormal
-            } else if self.is_subnormal() {
-                Class::NegativeSubnormal
-            } else {
-                Class::NegativeZero
+    fn divide_coefficients(
+        sign: bool,
+        ca: u128,
+        cb: u128,
+        ideal: i32,
+        mode: RoundingMode,
+    ) -> (Self, Flags) {
+        // ca/cb * 10^ideal, for a nonzero divisor of no more
+        // than 19 digits
+        if ca == 0 {
+            return d32::finalize(sign, 0, ideal, mode);
+        }
+        // scale the dividend so the quotient has at least
+        // one digit more than the precision, which is all
+        // we need to round correctly
+        let shift =
+            (PRECISION as i32 + 1 + digit_count(cb) as i32 - digit_count(ca) as i32).max(0) as u32;
+        let dividend = ca * pow10(shift);
+        let mut q = dividend / cb;
+        let mut exponent = ideal - shift as i32;
+        if !dividend.is_multiple_of(cb) {
+            // a nonzero remainder just has to be seen as a
+            // sticky digit below everything we computed
+            q = q * 10 + 1;
+            exponent -= 1;
+        } else {
+            // exact, so give back as many of the padding
+            // zeros as it takes to reach the ideal exponent
+            while exponent < ideal && q.is_multiple_of(10) {
+                q /= 10;
+                exponent += 1;
             }
-        } else if self.is_infinite() {
-            Class::PositiveInf
-        } else if self.is_normal() {
-            Class::PositiveNormal
-        } else if self.is_subnormal() {
-            Class::PositiveSubnormal
+        }
+        d32::finalize(sign, q, exponent, mode)
+    }
+
+    fn compare_value(&self, y: &d32) -> Option<core::cmp::Ordering> {
+        // numeric comparison: nans are unordered, zeros of
+        // either sign are equal, and members of a cohort are
+        // all the same number
+        if self.is_nan() || y.is_nan() {
+            return None;
+        }
+        // the sign alone decides, with zero in the middle,
+        // unless they match
+        let signum = |x: &d32| match (x.is_zero(), x.is_sign_minus()) {
+            (true, _) => 0,
+            (false, true) => -1,
+            (false, false) => 1,
+        };
+        let (sa, sb) = (signum(self), signum(y));
+        if sa != sb || sa == 0 {
+            return Some(sa.cmp(&sb));
+        }
+        // same sign, both nonzero: compare magnitudes, then
+        // flip it for negatives
+        let magnitude = if self.is_infinite() || y.is_infinite() {
+            self.is_infinite().cmp(&y.is_infinite())
         } else {
-            Class::PositiveZero
+            let (_, ca, ea) = self.decode();
+            let (_, cb, eb) = y.decode();
+            let (da, db) = (digit_count(ca as u128), digit_count(cb as u128));
+            // adjusted exponents first, then the coefficients
+            // padded out to the same number of digits
+            (ea + da as i32)
+                .cmp(&(eb + db as i32))
+                .then((ca * 10u64.pow(PRECISION - da)).cmp(&(cb * 10u64.pow(PRECISION - db))))
+        };
+        Some(if sa < 0 {
+            magnitude.reverse()
+        } else {
+            magnitude
+        })
+    }
+
+    fn order_key(&self) -> OrderKey {
+        // the magnitude as total_order sees it: finites, then
+        // infinity, then signaling and quiet nans by payload;
+        // finites go by adjusted exponent, then coefficient
+        // padded out to full precision, then the exponent to
+        // put a cohort in order (zeros come before the rest)
+        if self.is_nan() {
+            return OrderKey {
+                rank: if self.is_signaling() { 2 } else { 3 },
+                adjusted: 0,
+                padded: self.nan_payload(),
+                exponent: 0,
+            };
+        }
+        if self.is_infinite() {
+            return OrderKey {
+                rank: 1,
+                adjusted: 0,
+                padded: 0,
+                exponent: 0,
+            };
+        }
+        let (_, c, e) = self.decode();
+        let (adjusted, padded) = if c == 0 {
+            (i32::MIN, 0)
+        } else {
+            let digits = digit_count(c as u128);
+            (e + digits as i32, c as u32 * 10u32.pow(PRECISION - digits))
+        };
+        OrderKey {
+            rank: 0,
+            adjusted,
+            padded,
+            exponent: e,
         }
     }
 
-    pub fn is_sign_minus(&self) -> bool {
-        self.0 & 0x80000000 == 0x80000000
+    fn compare_signaling(&self, y: &d32) -> (Option<core::cmp::Ordering>, Flags) {
+        // numeric comparison again, except that any nan,
+        // quiet ones included, is an invalid operation
+        let flags = Flags {
+            invalid: self.is_nan() || y.is_nan(),
+            ..Flags::default()
+        };
+        (self.compare_value(y), flags)
     }
 
-    pub fn is_normal(&self) -> bool {
-        self.is_finite() && !self.is_zero() && !self.is_subnormal()
+    fn round_integral(&self, mode: RoundingMode) -> Option<(bool, u128, bool)> {
+        // rounds a finite value to an integer, giving back
+        // its sign, magnitude (saturated well past any
+        // integer type), and whether anything was lost
+        if !self.is_finite() {
+            return None;
+        }
+        let (sign, c, e) = self.decode();
+        if e >= 0 {
+            let magnitude = if c == 0 {
+                0
+            } else if e > 30 {
+                i128::MAX as u128
+            } else {
+                c as u128 * pow10(e as u32)
+            };
+            return Some((sign, magnitude, false));
+        }
+        let (magnitude, inexact) = round_off(c as u128, -e as u32, sign, mode);
+        Some((sign, magnitude as u128, inexact))
     }
 
-    pub fn is_finite(&self) -> bool {
-        !(self.is_infinite() || self.is_nan())
+    fn integral(&self, mode: RoundingMode) -> (Self, Flags) {
+        // rounds to an integer value with exponent zero,
+        // unless it was already above that; the flags are
+        // left to the caller to keep or not
+        if self.is_nan() {
+            return self.propagate_nan(self);
+        }
+        if self.is_infinite() {
+            return (*self, Flags::default());
+        }
+        let (sign, c, e) = self.decode();
+        if e >= 0 {
+            return (d32::encode(sign, c, e), Flags::default());
+        }
+        let (q, inexact) = round_off(c as u128, -e as u32, sign, mode);
+        let flags = Flags {
+            inexact,
+            ..Flags::default()
+        };
+        (d32::encode(sign, q, 0), flags)
     }
 
-    pub fn is_zero(&self) -> bool {
-        // need combination field to not indicate inf or nan,
-        self.is_finite()
-        // and need significand to be zero
-        && (self.significand() == 0 || !self.is_canonical())
+    fn sum(a: (bool, u128, i32), b: (bool, u128, i32), mode: RoundingMode) -> (Self, Flags) {
+        // adds two exact values given as (sign, coefficient,
+        // exponent), with coefficients of up to 14 digits;
+        // line up so that a has the larger exponent
+        let (a, b) = if a.2 >= b.2 { (a, b) } else { (b, a) };
+        let (sa, ca, ea) = a;
+        let (sb, mut cb, mut eb) = b;
+        let mut shift = (ea - eb) as u32;
+        // if b sits entirely below a's rounding digit, it can
+        // only ever matter as a sticky digit, so swap it for a
+        // single unit just below where a's digits would end
+        if ca != 0 && eb + digit_count(cb) as i32 <= ea - 10 {
+            shift = 11;
+            eb = ea - 11;
+            cb = (cb != 0) as u128;
+        }
+        let ca = if ca == 0 { 0 } else { ca * pow10(shift) };
+
+        let (sign, coefficient) = if sa == sb {
+            (sa, ca + cb)
+        } else if ca > cb {
+            (sa, ca - cb)
+        } else if cb > ca {
+            (sb, cb - ca)
+        } else {
+            // exact zero sum of opposite signs is +0, save
+            // for when we're rounding toward negative
+            (mode == RoundingMode::TowardNegative, 0)
+        };
+        d32::finalize(sign, coefficient, eb, mode)
     }
 
-    pub fn is_subnormal(&self) -> bool {
-        self.is_finite()
-            && !self.is_zero()
-            && self.exponent() < 6
-            && self.significand() * 10u64.pow(self.exponent()) < 1000000
+    /* ********************************************** *
+     *                  CONSTRUCTORS                  *
+     * ********************************************** */
+
+    pub fn from_parts(sign: bool, coefficient: u32, exponent: i32) -> Self {
+        // anything that doesn't fit exactly gets rounded to
+        // nearest: extra digits round away, exponents too
+        // large overflow to infinity (after padding zeros
+        // in, if that's enough), and too small go subnormal
+        d32::finalize(
+            sign,
+            coefficient as u128,
+            exponent,
+            RoundingMode::TiesToEven,
+        )
+        .0
     }
 
-    pub fn is_infinite(&self) -> bool {
-        !self.is_nan() && (self.0 & 0x78000000 == 0x78000000)
+    pub fn try_from_parts(sign: bool, coefficient: u32, exponent: i32) -> Result<Self, BuildError> {
+        // from_parts without the rounding: the parts go in as
+        // they are or not at all, so the exponent has to be a
+        // quantum exponent, qmin..=qmax, even where padding
+        // zeros in would have kept the value exact
+        if coefficient as u64 > MAX_COEFFICIENT {
+            return Err(BuildError::CoefficientTooLarge);
+        }
+        if !(QMIN..=QMAX).contains(&exponent) {
+            return Err(BuildError::ExponentOutOfRange);
+        }
+        Ok(d32::encode(sign, coefficient as u64, exponent))
     }
 
-    pub fn is_nan(&self) -> bool {
-        self.0 & 0x7c000000 == 0x7c000000
+    /// Takes the encoding as-is, without canonicalizing it:
+    /// noncanonical declets and stray bits in specials are
+    /// kept, and `to_bits` gives back exactly these bits.
+    pub const fn from_bits(bits: u32) -> Self {
+        d32(bits)
     }
 
-    pub fn is_signaling(&self) -> bool {
-        self.0 & 0x7e000000 == 0x7e000000
+    pub const fn to_bits(&self) -> u32 {
+        self.0
     }
 
-    pub fn is_canonical(&self) -> bool {
-        (self.is_nan() && self.0 & 0x7df00000 == 0x7c000000)
-            || (self.is_infinite() && self.0 & 0x7fffffff == 0x78000000)
-            || (self.is_finite() && self.significand() <= 9999999)
+    /// For constants: the exponent comes biased by 101, so
+    /// 0..=191 covers qmin to qmax, and nothing is rounded.
+    /// `from_raw_parts(false, 825, 97)` is 0.0825. Out of range
+    /// parts panic, which in a const is a compile error.
+    pub const fn from_raw_parts(sign: bool, coefficient: u32, biased_exponent: u16) -> Self {
+        assert!(
+            coefficient as u64 <= MAX_COEFFICIENT,
+            "coefficient over seven digits"
+        );
+        assert!(
+            biased_exponent as i32 <= QMAX + BIAS,
+            "biased exponent over 191"
+        );
+        d32::encode(sign, coefficient as u64, biased_exponent as i32 - BIAS)
     }
 
-    pub fn radix(&self) -> u32 {
-        10
+    /// The standard fixes the encoding as a bit string but not
+    /// its byte order; big-endian puts the sign and combination
+    /// field first, as the bit string reads, and is the usual
+    /// choice on the wire. Little-endian matches a `_Decimal32`
+    /// in memory on x86 and most ARM.
+    pub const fn to_be_bytes(&self) -> [u8; 4] {
+        self.0.to_be_bytes()
     }
 
-    pub fn total_order(&self, y: &d32) -> bool {
-        match (self.class(), y.class()) {
-            (Class::QuietNaN, Class::QuietNaN) => {
-                (self.is_sign_minus() && !y.is_sign_minus())
-                    || (self.significand() == y.significand())
-            }
-            (Class::SignalingNaN, Class::SignalingNaN) => {
-                self.is_sign_minus() && !y.is_sign_minus()
-                    || (self.significand() == y.significand())
-            }
-            (Class::QuietNaN, Class::SignalingNaN) => self.is_sign_minus(),
-            (Class::SignalingNaN, Class::QuietNaN) => !y.is_sign_minus(),
-            (Class::QuietNaN, _) => self.is_sign_minus(),
-            (Class::SignalingNaN, _) => self.is_sign_minus(),
-            (_, Class::QuietNaN) => !y.is_sign_minus(),
-            (_, Class::SignalingNaN) => !y.is_sign_minus(),
-            (Class::NegativeInf, Class::NegativeInf) => true,
-            (Class::NegativeInf, _) => true,
-            (_, Class::NegativeInf) => false,
-            (Class::PositiveInf, Class::PositiveInf) => true,
-            (Class::PositiveInf, _) => false,
-            (_, Class::PositiveInf) => true,
-            (Class::NegativeNormal, Class::NegativeNormal) => {
-                (self.significand() > y.significand() && self.exponent() >= y.exponent())
-                    || (self.exponent() - y.exponent() > 6)
-                    || (self.exponent() >= y.exponent()
-                        && (self.significand() * 10u64.pow(self.exponent() - y.exponent())
-                            >= y.significand()))
-                    || (self.exponent() <= y.exponent()
-                        && (self.significand()
-                            > y.significand() * 10u64.pow(y.exponent() - self.exponent())))
-            }
-            (Class::NegativeNormal, _) => true,
-            (_, Class::NegativeNormal) => false,
-            (Class::NegativeSubnormal, Class::NegativeSubnormal) => {
-                (self.significand() > y.significand() && self.exponent() >= y.exponent())
-                    || (self.exponent() - y.exponent() > 6)
-                    || (self.exponent() >= y.exponent()
-                        && (self.significand() * 10u64.pow(self.exponent() - y.exponent())
-                            >= y.significand()))
-                    || (self.exponent() < y.exponent()
-                        && (self.significand()
-                            > y.significand() * 10u64.pow(y.exponent() - self.exponent())))
-            }
-            (Class::NegativeSubnormal, _) => true,
-            (_, Class::NegativeSubnormal) => false,
-            (Class::NegativeZero, Class::NegativeZero) => self.exponent() >= y.exponent(),
-            (Class::NegativeZero, _) => true,
-            (_, Class::NegativeZero) => false,
-            (Class::PositiveZero, Class::PositiveZero) => self.exponent() <= y.exponent(),
-            (Class::PositiveZero, _) => true,
-            (_, Class::PositiveZero) => false,
-            (Class::PositiveSubnormal, Class::PositiveSubnormal) => {
-                (self.significand() < y.significand() && self.exponent() <= y.exponent())
-                    || (y.exponent() - self.exponent() > 6)
-                    || (self.exponent() <= y.exponent()
-                        && (self.significand()
-                            <= y.significand() * 10u64.pow(y.exponent() - self.exponent())))
-                    || (self.exponent() > y.exponent()
-                        && (self.significand() * 10u64.pow(self.exponent() - y.exponent())
-                            < y.significand()))
-            }
-            (Class::PositiveSubnormal, _) => true,
-            (_, Class::PositiveSubnormal) => false,
-            (Class::PositiveNormal, Class::PositiveNormal) => {
-                (self.significand() < y.significand() && self.exponent() <= y.exponent())
-                    || (y.exponent() - self.exponent() > 6)
-                    || (self.exponent() <= y.exponent()
-                        && (self.significand()
-                            <= y.significand() * 10u64.pow(y.exponent() - self.exponent())))
-                    || (self.exponent() > y.exponent()
-                        && (self.significand() * 10u64.pow(self.exponent() - y.exponent())
-                            < y.significand()))
+    pub const fn to_le_bytes(&self) -> [u8; 4] {
+        self.0.to_le_bytes()
+    }
+
+    pub const fn from_be_bytes(bytes: [u8; 4]) -> Self {
+        d32(u32::from_be_bytes(bytes))
+    }
+
+    pub const fn from_le_bytes(bytes: [u8; 4]) -> Self {
+        d32(u32::from_le_bytes(bytes))
+    }
+
+    pub fn from_i64(value: i64, mode: RoundingMode) -> Self {
+        // unsigned_abs sidesteps i64::MIN having no positive
+        // counterpart
+        d32::finalize(value < 0, value.unsigned_abs() as u128, 0, mode).0
+    }
+
+    pub fn from_u64(value: u64, mode: RoundingMode) -> Self {
+        d32::finalize(false, value as u128, 0, mode).0
+    }
+
+    pub fn from_ratio(num: i64, den: i64, mode: RoundingMode) -> (Self, Flags) {
+        // num/den rounded just the once, as an exact division
+        // of two integers would be; the ideal exponent is zero
+        let sign = (num < 0) != (den < 0);
+        if den == 0 {
+            if num == 0 {
+                return d32::invalid();
             }
+            let flags = Flags {
+                div_by_zero: true,
+                ..Flags::default()
+            };
+            return (d32::infinity(sign), flags);
         }
+        let (ca, cb) = (num.unsigned_abs() as u128, den.unsigned_abs() as u128);
+        d32::divide_coefficients(sign, ca, cb, 0, mode)
     }
 
-    pub fn total_order_mag(&self, y: &d32) -> bool {
-        self.abs().total_order(&y.abs())
+    pub fn from_f64(value: f64, mode: RoundingMode) -> Self {
+        let sign = value.is_sign_negative();
+        if value.is_nan() {
+            return d32::nan_with_payload(sign, false, 0);
+        }
+        if value.is_infinite() {
+            return d32::infinity(sign);
+        }
+        // every finite binary value is m * 2^k, which is
+        // exactly m * 5^-k * 10^k when k is negative; with
+        // m odd, those digits have no trailing zeros to trim
+        let bits = value.to_bits();
+        let biased = ((bits >> 52) & 0x7ff) as i32;
+        let fraction = bits & 0x000fffffffffffff;
+        let (mut m, mut k) = if biased == 0 {
+            (fraction, -1074)
+        } else {
+            (fraction | 0x0010000000000000, biased - 1075)
+        };
+        if m == 0 {
+            return d32::encode(sign, 0, 0);
+        }
+        let zeros = m.trailing_zeros();
+        m >>= zeros;
+        k += zeros as i32;
+        let (digits, exponent) = if k >= 0 {
+            (exact_digits(m, 2, k as u32), 0)
+        } else {
+            (exact_digits(m, 5, -k as u32), k as i64)
+        };
+        d32::round_digits(sign, digits, exponent, mode).0
     }
 
-    pub fn same_quantum(&self, y: &d32) -> bool {
-        (self.is_nan() && y.is_nan())
-            || (self.is_infinite() && y.is_infinite())
-            || (self.is_finite() && y.is_finite() && self.exponent() == y.exponent())
+    pub fn from_f32(value: f32, mode: RoundingMode) -> Self {
+        // widening to f64 is exact, subnormals included, so
+        // this rounds only once
+        d32::from_f64(value as f64, mode)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::time::Instant;
-    /*  don't actually run this test
-        i did it merely out of paranoia, this having been my first test of the library
-        exhaustive testing on 32 bits is, to say the least, impractical, and to say the most,
-        fucking nonsense
-        
-        #[test]
-        fn total_order_test() {
-            let now = Instant::now();
-
-            for i in 0..=u32::MAX {
-                assert!(d32(i).total_order(&d32(i)));
+    #[doc(hidden)]
+    pub const fn from_literal(s: &str) -> Self {
+        // for dec32!: parses a literal at compile time, keeping
+        // its cohort, and panics (so fails to compile) on
+        // anything it can't represent exactly; spaces are
+        // skipped, since stringify! may put one after the sign
+        let bytes = s.as_bytes();
+        let mut i = 0;
+        let mut sign = false;
+        let mut coefficient = 0u64;
+        let mut significant = 0;
+        let mut any_digits = false;
+        let mut point = false;
+        let mut fraction = 0i32;
+        while i < bytes.len() && bytes[i] == b' ' {
+            i += 1;
+        }
+        if i < bytes.len() && (bytes[i] == b'-' || bytes[i] == b'+') {
+            sign = bytes[i] == b'-';
+            i += 1;
+        }
+        while i < bytes.len() && bytes[i] == b' ' {
+            i += 1;
+        }
+        while i < bytes.len() && bytes[i] != b'e' && bytes[i] != b'E' {
+            let c = bytes[i];
+            if c == b'.' && !point {
+                point = true;
+            } else if c.is_ascii_digit() {
+                coefficient = coefficient * 10 + (c - b'0') as u64;
+                if coefficient != 0 {
+                    significant += 1;
+                }
+                if significant > PRECISION {
+                    panic!("more than seven significant digits");
+                }
+                if point {
+                    fraction += 1;
+                }
+                any_digits = true;
+            } else if c != b'_' {
+                panic!("not a decimal literal");
+            }
+            i += 1;
+        }
+        if !any_digits {
+            panic!("not a decimal literal");
+        }
+        let mut exponent = 0i32;
+        if i < bytes.len() {
+            i += 1;
+            let negative = i < bytes.len() && bytes[i] == b'-';
+            if i < bytes.len() && (bytes[i] == b'-' || bytes[i] == b'+') {
+                i += 1;
+            }
+            if i == bytes.len() {
+                panic!("missing exponent");
+            }
+            while i < bytes.len() {
+                let c = bytes[i];
+                if c.is_ascii_digit() {
+                    if exponent > 1000 {
+                        panic!("exponent out of range");
+                    }
+                    exponent = exponent * 10 + (c - b'0') as i32;
+                } else if c != b'_' {
+                    panic!("not a decimal literal");
+                }
+                i += 1;
             }
+            if negative {
+                exponent = -exponent;
+            }
+        }
+        // an exponent out of range is fine so long as moving
+        // zeros in or out of the coefficient fixes it
+        let mut exponent = exponent - fraction;
+        while exponent > QMAX && coefficient * 10 <= MAX_COEFFICIENT {
+            coefficient *= 10;
+            exponent -= 1;
+        }
+        while exponent < QMIN && coefficient.is_multiple_of(10) {
+            coefficient /= 10;
+            exponent += 1;
+        }
+        if exponent > QMAX || exponent < QMIN {
+            panic!("exponent out of range");
+        }
+        d32::encode(sign, coefficient, exponent)
+    }
 
-            let elapsed = now.elapsed();
+    pub fn from_digits(
+        sign: bool,
+        digits: &[u8],
+        exponent: i32,
+        mode: RoundingMode,
+    ) -> (Self, Flags) {
+        // the value of big-endian decimal digits times 10^exponent,
+        // rounded once however many digits there are; no digits
+        // is zero, and anything over nine is invalid
+        if digits.iter().any(|&d| d > 9) {
+            return d32::invalid();
+        }
+        d32::round_digits(sign, digits.iter().copied(), exponent as i64, mode)
+    }
 
-            println!("Time: {} micros", elapsed.as_micros());
-            println!("Time per op: {} micros", elapsed.as_micros() as f64 / u32::MAX as f64);
+    pub fn parse_with_options(s: &str, options: ParseOptions) -> Result<Self, ParseDecimalError> {
+        // a point or separator that could be mistaken for part
+        // of the number makes for options nothing can match
+        let ambiguous = |c: char| c.is_ascii_digit() || "eE+-".contains(c);
+        let point = options.decimal_point;
+        if ambiguous(point)
+            || options
+                .thousands_separator
+                .is_some_and(|c| ambiguous(c) || c == point)
+        {
+            return Err(ParseDecimalError::InvalidDigit);
         }
-    */
-}
\ No newline at end of file
+        let (sign, rest) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+        if rest.is_empty() {
+            return Err(ParseDecimalError::Empty);
+        }
+
+        if rest.eq_ignore_ascii_case("inf") || rest.eq_ignore_ascii_case("infinity") {
+            return Ok(d32::infinity(sign));
+        }
+        if let Some(payload) = strip_prefix_ignore_case(rest, "snan") {
+            return parse_payload(payload).map(|p| d32::nan_with_payload(sign, true, p));
+        }
+        if let Some(payload) = strip_prefix_ignore_case(rest, "nan") {
+            return parse_payload(payload).map(|p| d32::nan_with_payload(sign, false, p));
+        }
+
+        let (mantissa, exponent) = match rest.find(['e', 'E']) {
+            Some(i) => (&rest[..i], parse_exponent(&rest[i + 1..])?),
+            None => (rest, 0),
+        };
+        let (int, frac) = match mantissa.find(point) {
+            Some(i) => (&mantissa[..i], &mantissa[i + point.len_utf8()..]),
+            None => (mantissa, ""),
+        };
+        if int.is_empty() && frac.is_empty() {
+            return Err(ParseDecimalError::InvalidDigit);
+        }
+
+        // groups are only in the integer part: one to three
+        // digits up front, then exactly three after each
+        // separator
+        let separator = options.thousands_separator;
+        if let Some(separator) = separator.filter(|&c| int.contains(c)) {
+            let mut groups = int.split(separator);
+            let first = groups.next().map_or(0, str::len);
+            if !(1..=3).contains(&first) || groups.any(|g| g.len() != 3) {
+                return Err(ParseDecimalError::InvalidGrouping);
+            }
+        }
+
+        // the literal's own exponent is kept, so every digit
+        // counts, trailing zeros included
+        let digits = int
+            .chars()
+            .filter(|&c| Some(c) != separator)
+            .chain(frac.chars());
+        if !digits.clone().all(|c| c.is_ascii_digit()) {
+            return Err(ParseDecimalError::InvalidDigit);
+        }
+        let exponent = exponent - frac.len() as i64;
+        let digits = digits.map(|c| c as u8 - b'0');
+        Ok(d32::round_digits(sign, digits, exponent, RoundingMode::TiesToEven).0)
+    }
+
+    pub fn nan(sign: bool, payload: u32) -> Self {
+        // only six digits fit in the trailing field, so
+        // anything above that loses its leading digits
+        d32::nan_with_payload(sign, false, payload % 1000000)
+    }
+
+    pub fn signaling_nan(sign: bool, payload: u32) -> Self {
+        d32::nan_with_payload(sign, true, payload % 1000000)
+    }
+
+    pub fn from_d64(x: d64, mode: RoundingMode) -> Self {
+        d32::from_d64_with_flags(x, mode).0
+    }
+
+    pub fn from_d64_with_flags(x: d64, mode: RoundingMode) -> (Self, Flags) {
+        // nans keep what they can of the payload (its last
+        // six digits) and get quieted; finite values round
+        // like any other result
+        if x.is_nan() {
+            let flags = Flags {
+                invalid: x.is_signaling(),
+                ..Flags::default()
+            };
+            let payload = (x.nan_payload() % 1000000) as u32;
+            return (d32::nan(x.is_sign_minus(), payload), flags);
+        }
+        if x.is_infinite() {
+            return (d32::infinity(x.is_sign_minus()), Flags::default());
+        }
+        let (sign, c, e) = x.decode();
+        d32::finalize(sign, c as u128, e, mode)
+    }
+
+    /* ********************************************** *
+     *             GENERAL-COMP FUNCTIONS             *
+     * ********************************************** */
+
+    pub fn add(&self, y: &d32, mode: RoundingMode) -> Self {
+        self.add_with_flags(y, mode).0
+    }
+
+    pub fn add_with_flags(&self, y: &d32, mode: RoundingMode) -> (Self, Flags) {
+        if self.is_nan() || y.is_nan() {
+            return self.propagate_nan(y);
+        }
+        if self.is_infinite() && y.is_infinite() && self.is_sign_minus() != y.is_sign_minus() {
+            return d32::invalid();
+        }
+        if self.is_infinite() || y.is_infinite() {
+            let sign = if self.is_infinite() {
+                self.is_sign_minus()
+            } else {
+                y.is_sign_minus()
+            };
+            return (d32::infinity(sign), Flags::default());
+        }
+
+        let (sa, ca, ea) = self.decode();
+        let (sb, cb, eb) = y.decode();
+        d32::sum((sa, ca as u128, ea), (sb, cb as u128, eb), mode)
+    }
+
+    pub fn sum_exact(values: &[d32], mode: RoundingMode) -> (Self, Flags) {
+        // the whole sum, exactly, with only one rounding at
+        // the end; nans go by the usual precedence, and
+        // infinities of both signs are invalid
+        if let Some(first) = values.iter().find(|x| x.is_nan()) {
+            let nan = values.iter().find(|x| x.is_signaling()).unwrap_or(first);
+            return nan.propagate_nan(nan);
+        }
+        let positive = values.iter().any(|x| x.is_infinite() && !x.is_sign_minus());
+        let negative = values.iter().any(|x| x.is_infinite() && x.is_sign_minus());
+        if positive && negative {
+            return d32::invalid();
+        }
+        if positive || negative {
+            return (d32::infinity(negative), Flags::default());
+        }
+        if values.is_empty() {
+            return (d32::ZERO, Flags::default());
+        }
+
+        // one signed column per exponent, with room above
+        // qmax for carries; an i64 column takes billions of
+        // coefficients before it could overflow
+        const COLUMNS: usize = (QMAX - QMIN + 1) as usize + 25;
+        let mut columns = [0i64; COLUMNS];
+        let mut lowest = QMAX;
+        for x in values {
+            let (sign, c, e) = x.decode();
+            let c = c as i64;
+            columns[(e - QMIN) as usize] += if sign { -c } else { c };
+            lowest = lowest.min(e);
+        }
+        let normalize = |columns: &mut [i64; COLUMNS]| {
+            for i in 0..COLUMNS - 1 {
+                let carry = columns[i].div_euclid(10);
+                columns[i] = columns[i].rem_euclid(10);
+                columns[i + 1] += carry;
+            }
+        };
+        // a negative total leaves the borrow in the top
+        // column, so flip everything and carry again
+        normalize(&mut columns);
+        let sign = columns[COLUMNS - 1] < 0;
+        if sign {
+            columns.iter_mut().for_each(|d| *d = -*d);
+            normalize(&mut columns);
+        }
+
+        let low = (lowest - QMIN) as usize;
+        let digits = columns[low..].iter().rev().map(|&d| d as u8);
+        if digits.clone().all(|d| d == 0) {
+            // same rule as add: zeros of one sign keep it, and
+            // anything else is +0 unless rounding down
+            let first = values[0].is_sign_minus();
+            let sign = if values
+                .iter()
+                .all(|x| x.is_zero() && x.is_sign_minus() == first)
+            {
+                first
+            } else {
+                mode == RoundingMode::TowardNegative
+            };
+            return d32::finalize(sign, 0, lowest, mode);
+        }
+        d32::round_digits(sign, digits, lowest as i64, mode)
+    }
+
+    pub fn subtract(&self, y: &d32, mode: RoundingMode) -> Self {
+        self.subtract_with_flags(y, mode).0
+    }
+
+    pub fn subtract_with_flags(&self, y: &d32, mode: RoundingMode) -> (Self, Flags) {
+        // nans pass through as-is rather than picking up
+        // the flipped sign; everything else is just adding
+        // the negation, so the exponent and zero-sign rules
+        // are exactly those of add
+        if self.is_nan() || y.is_nan() {
+            return self.propagate_nan(y);
+        }
+        self.add_with_flags(&y.negate(), mode)
+    }
+
+    pub fn abs_sub(&self, y: &d32, mode: RoundingMode) -> Self {
+        self.abs_sub_with_flags(y, mode).0
+    }
+
+    pub fn abs_sub_with_flags(&self, y: &d32, mode: RoundingMode) -> (Self, Flags) {
+        // subtract the smaller from the larger, so that the
+        // one rounding already goes the right way for a
+        // positive result
+        if self.is_nan() || y.is_nan() {
+            return self.propagate_nan(y);
+        }
+        let (a, b) = if self < y { (y, self) } else { (self, y) };
+        let (difference, flags) = a.subtract_with_flags(b, mode);
+        (difference.abs(), flags)
+    }
+
+    pub fn saturating_add(&self, y: &d32, mode: RoundingMode) -> Self {
+        self.saturating_add_with_flags(y, mode).0
+    }
+
+    pub fn saturating_add_with_flags(&self, y: &d32, mode: RoundingMode) -> (Self, Flags) {
+        // overflow stops at the largest finite number, though
+        // it's still flagged; infinite operands stay infinite
+        let (sum, flags) = self.add_with_flags(y, mode);
+        if flags.overflow && sum.is_infinite() {
+            return (d32::MAX.copy_sign(&sum), flags);
+        }
+        (sum, flags)
+    }
+
+    pub fn multiply(&self, y: &d32, mode: RoundingMode) -> Self {
+        self.multiply_with_flags(y, mode).0
+    }
+
+    pub fn multiply_with_flags(&self, y: &d32, mode: RoundingMode) -> (Self, Flags) {
+        if self.is_nan() || y.is_nan() {
+            return self.propagate_nan(y);
+        }
+        let sign = self.is_sign_minus() != y.is_sign_minus();
+        if self.is_infinite() || y.is_infinite() {
+            // 0 * inf has no sensible value
+            if self.is_zero() || y.is_zero() {
+                return d32::invalid();
+            }
+            return (d32::infinity(sign), Flags::default());
+        }
+
+        // the exact product has the sum of the exponents,
+        // which is also the preferred one
+        let (_, ca, ea) = self.decode();
+        let (_, cb, eb) = y.decode();
+        d32::finalize(sign, ca as u128 * cb as u128, ea + eb, mode)
+    }
+
+    pub fn fused_multiply_add(&self, y: &d32, z: &d32, mode: RoundingMode) -> Self {
+        self.fused_multiply_add_with_flags(y, z, mode).0
+    }
+
+    pub fn fused_multiply_add_with_flags(
+        &self,
+        y: &d32,
+        z: &d32,
+        mode: RoundingMode,
+    ) -> (Self, Flags) {
+        if self.is_nan() || y.is_nan() || z.is_nan() {
+            // same precedence as with two operands, with z last
+            let z_first = z.is_signaling() && !self.is_signaling() && !y.is_signaling();
+            if z_first || !(self.is_nan() || y.is_nan()) {
+                let (nan, mut flags) = z.propagate_nan(z);
+                flags.invalid |= self.is_signaling() || y.is_signaling();
+                return (nan, flags);
+            }
+            let (nan, mut flags) = self.propagate_nan(y);
+            flags.invalid |= z.is_signaling();
+            return (nan, flags);
+        }
+        let sign = self.is_sign_minus() != y.is_sign_minus();
+        if (self.is_infinite() || y.is_infinite()) && (self.is_zero() || y.is_zero()) {
+            // 0 * inf is invalid no matter what z is
+            return d32::invalid();
+        }
+        if self.is_infinite() || y.is_infinite() {
+            if z.is_infinite() && z.is_sign_minus() != sign {
+                return d32::invalid();
+            }
+            return (d32::infinity(sign), Flags::default());
+        }
+        if z.is_infinite() {
+            return (d32::infinity(z.is_sign_minus()), Flags::default());
+        }
+
+        // keep all 14 digits of the product so that the
+        // only rounding happens after the addition
+        let (_, ca, ea) = self.decode();
+        let (_, cb, eb) = y.decode();
+        let (sz, cz, ez) = z.decode();
+        d32::sum(
+            (sign, ca as u128 * cb as u128, ea + eb),
+            (sz, cz as u128, ez),
+            mode,
+        )
+    }
+
+    pub fn mul_add(&self, y: &d32, z: &d32) -> Self {
+        // f64::mul_add's signature, rounding to nearest
+        self.fused_multiply_add(y, z, RoundingMode::TiesToEven)
+    }
+
+    pub fn divide(&self, y: &d32, mode: RoundingMode) -> Self {
+        self.divide_with_flags(y, mode).0
+    }
+
+    pub fn divide_with_flags(&self, y: &d32, mode: RoundingMode) -> (Self, Flags) {
+        if self.is_nan() || y.is_nan() {
+            return self.propagate_nan(y);
+        }
+        let sign = self.is_sign_minus() != y.is_sign_minus();
+        if self.is_infinite() {
+            if y.is_infinite() {
+                return d32::invalid();
+            }
+            return (d32::infinity(sign), Flags::default());
+        }
+        if y.is_infinite() {
+            return (d32::encode(sign, 0, QMIN), Flags::default());
+        }
+        if y.is_zero() {
+            if self.is_zero() {
+                return d32::invalid();
+            }
+            let flags = Flags {
+                div_by_zero: true,
+                ..Flags::default()
+            };
+            return (d32::infinity(sign), flags);
+        }
+
+        let (_, ca, ea) = self.decode();
+        let (_, cb, eb) = y.decode();
+        d32::divide_coefficients(sign, ca as u128, cb as u128, ea - eb, mode)
+    }
+
+    pub fn remainder(&self, y: &d32) -> Self {
+        self.remainder_with_flags(y).0
+    }
+
+    pub fn remainder_with_flags(&self, y: &d32) -> (Self, Flags) {
+        if self.is_nan() || y.is_nan() {
+            return self.propagate_nan(y);
+        }
+        if self.is_infinite() || y.is_zero() {
+            return d32::invalid();
+        }
+        if y.is_infinite() {
+            return (*self, Flags::default());
+        }
+
+        // x - y * n for the nearest (ties to even) integer n
+        // to x / y; it's always exact, at the smaller of the
+        // two exponents. working mod 2|y| tells us both the
+        // remainder and whether the truncated quotient is odd
+        let (sign, cx, ex) = self.decode();
+        let (_, cy, ey) = y.decode();
+        let exponent = ex.min(ey);
+        let (r, y) = if ex >= ey {
+            let y = cy as u128;
+            let mut r = cx as u128 % (2 * y);
+            for _ in 0..ex - ey {
+                r = r * 10 % (2 * y);
+            }
+            (r, y)
+        } else if ey - ex <= 20 {
+            let y = cy as u128 * pow10((ey - ex) as u32);
+            (cx as u128 % (2 * y), y)
+        } else {
+            // y dwarfs x, which then is its own remainder
+            return (*self, Flags::default());
+        };
+        let (odd, r) = if r >= y { (true, r - y) } else { (false, r) };
+        let (sign, magnitude) = match (2 * r).cmp(&y) {
+            core::cmp::Ordering::Greater => (!sign, y - r),
+            core::cmp::Ordering::Equal if odd => (!sign, y - r),
+            _ => (sign, r),
+        };
+        d32::finalize(sign, magnitude, exponent, RoundingMode::TiesToEven)
+    }
+
+    pub fn divide_integer(&self, y: &d32) -> Self {
+        self.divide_integer_with_flags(y).0
+    }
+
+    pub fn divide_integer_with_flags(&self, y: &d32) -> (Self, Flags) {
+        // the general decimal arithmetic's divideInteger: x / y
+        // truncated to an integer, always exact and at exponent
+        // zero, and invalid when that takes more than seven
+        // digits
+        if self.is_nan() || y.is_nan() {
+            return self.propagate_nan(y);
+        }
+        let sign = self.is_sign_minus() != y.is_sign_minus();
+        if self.is_infinite() {
+            if y.is_infinite() {
+                return d32::invalid();
+            }
+            return (d32::infinity(sign), Flags::default());
+        }
+        if y.is_infinite() {
+            return (d32::encode(sign, 0, 0), Flags::default());
+        }
+        if y.is_zero() {
+            if self.is_zero() {
+                return d32::invalid();
+            }
+            let flags = Flags {
+                div_by_zero: true,
+                ..Flags::default()
+            };
+            return (d32::infinity(sign), flags);
+        }
+        match self.divide_truncated(y) {
+            Some((q, _, _)) => (d32::encode(sign, q as u64, 0), Flags::default()),
+            None => d32::invalid(),
+        }
+    }
+
+    pub fn remainder_gda(&self, y: &d32) -> Self {
+        self.remainder_gda_with_flags(y).0
+    }
+
+    pub fn remainder_gda_with_flags(&self, y: &d32) -> (Self, Flags) {
+        // x - y * divide_integer(x, y), so unlike remainder it
+        // takes the sign of x, and is invalid whenever
+        // divide_integer is; exact, at the smaller exponent
+        if self.is_nan() || y.is_nan() {
+            return self.propagate_nan(y);
+        }
+        if self.is_infinite() || y.is_zero() {
+            return d32::invalid();
+        }
+        if y.is_infinite() {
+            return (*self, Flags::default());
+        }
+        match self.divide_truncated(y) {
+            Some((_, r, exponent)) => {
+                d32::finalize(self.is_sign_minus(), r, exponent, RoundingMode::TiesToEven)
+            }
+            None => d32::invalid(),
+        }
+    }
+
+    pub fn quantum(&self) -> Self {
+        // if it's a NaN, we want to canonicalize it
+        // and propagate its payload
+        if self.is_signaling() {
+            d32(self.0 & 0x7e0fffff)
+        } else if self.is_nan() {
+            d32(self.0 & 0x7c0fffff)
+        // if it's +/-inf, its quantum is +inf
+        } else if self.is_infinite() {
+            d32(0x78000000)
+        // for finite numbers, we clear the sign bit,
+        // leave the exponent untouched, and wipe out
+        // the significand, leaving 1
+        } else {
+            d32::encode(false, 1, self.exponent() as i32 - BIAS)
+        }
+    }
+
+    /// The spacing of the decimal grid at this magnitude: a
+    /// one in the last of seven digits, aligned with the
+    /// leading digit, so `1.000` and `1` alike have an ulp of
+    /// `1E-6`. That's where it differs from `quantum`, which
+    /// goes by the stored exponent; the two agree whenever all
+    /// seven digits are in use.
+    ///
+    /// Subnormals and zeros share the smallest subnormal,
+    /// `1E-101`, as their ulp, since below the normal range
+    /// the grid stops getting finer. Infinities give `+Inf`,
+    /// and nans come back quiet.
+    pub fn ulp(&self) -> Self {
+        if self.is_nan() {
+            return self.quieted();
+        }
+        if self.is_infinite() {
+            return d32::infinity(false);
+        }
+        let (_, c, e) = self.decode();
+        let adjusted = e + digit_count(c as u128) as i32 - 1;
+        if c == 0 {
+            d32::encode(false, 1, QMIN)
+        } else {
+            d32::encode(false, 1, (adjusted - PRECISION as i32 + 1).max(QMIN))
+        }
+    }
+
+    pub fn quantum_exponent(&self) -> Option<i32> {
+        // the same as the exponent as stored, which subnormals
+        // keep along with everyone else (most of them at qmin)
+        self.unbiased_exponent()
+    }
+
+    pub fn quantize(&self, y: &d32, mode: RoundingMode) -> Self {
+        self.quantize_with_flags(y, mode).0
+    }
+
+    pub fn quantize_with_flags(&self, y: &d32, mode: RoundingMode) -> (Self, Flags) {
+        if self.is_nan() || y.is_nan() {
+            return self.propagate_nan(y);
+        }
+        if self.is_infinite() || y.is_infinite() {
+            if self.is_infinite() && y.is_infinite() {
+                return (*self, Flags::default());
+            }
+            return d32::invalid();
+        }
+        let (_, _, target) = y.decode();
+        self.rescale_with_flags(target, mode)
+    }
+
+    pub fn rescale(&self, target: i32, mode: RoundingMode) -> Self {
+        self.rescale_with_flags(target, mode).0
+    }
+
+    pub fn rescale_with_flags(&self, target: i32, mode: RoundingMode) -> (Self, Flags) {
+        if self.is_nan() {
+            return self.propagate_nan(self);
+        }
+        if self.is_infinite() || !(QMIN..=QMAX).contains(&target) {
+            return d32::invalid();
+        }
+        // with the exponent in range, the only way this fails
+        // is by needing too many digits; it never overflows
+        // or underflows, just rounds
+        let (sign, c, e) = self.decode();
+        if target < e {
+            let shift = (e - target) as u32;
+            if c != 0 && digit_count(c as u128) + shift > PRECISION {
+                return d32::invalid();
+            }
+            let c = if c == 0 { 0 } else { c * pow10(shift) as u64 };
+            return (d32::encode(sign, c, target), Flags::default());
+        }
+        let (q, inexact) = round_off(c as u128, (target - e) as u32, sign, mode);
+        if q > MAX_COEFFICIENT {
+            return d32::invalid();
+        }
+        let flags = Flags {
+            inexact,
+            ..Flags::default()
+        };
+        (d32::encode(sign, q, target), flags)
+    }
+
+    /// Rounds to at most `significant_digits` digits, raising
+    /// the exponent by however many are dropped. Values that
+    /// already have few enough digits come back unchanged,
+    /// so nothing is ever padded out with zeros.
+    ///
+    /// Panics unless `significant_digits` is from 1 to 7.
+    pub fn round_to_digits(&self, significant_digits: u32, mode: RoundingMode) -> Self {
+        self.round_to_digits_with_flags(significant_digits, mode).0
+    }
+
+    pub fn round_to_digits_with_flags(
+        &self,
+        significant_digits: u32,
+        mode: RoundingMode,
+    ) -> (Self, Flags) {
+        assert!(
+            (1..=PRECISION).contains(&significant_digits),
+            "significant digits must be from 1 to 7"
+        );
+        if self.is_nan() {
+            return self.propagate_nan(self);
+        }
+        let (sign, c, e) = self.decode();
+        let digits = digit_count(c as u128);
+        if self.is_infinite() || digits <= significant_digits {
+            return (*self, Flags::default());
+        }
+        // a carry out of the top (9.99 to 10.0) leaves one
+        // digit too many, but that one's a zero; the only
+        // other way out is overflowing past max
+        let drop = digits - significant_digits;
+        let (mut q, inexact) = round_off(c as u128, drop, sign, mode);
+        let mut exponent = e + drop as i32;
+        if digit_count(q as u128) > significant_digits {
+            q /= 10;
+            exponent += 1;
+        }
+        let (x, flags) = d32::finalize(sign, q as u128, exponent, mode);
+        // tiny before rounding means it was subnormal to begin
+        // with, as far as underflow goes
+        let flags = Flags {
+            inexact: flags.inexact || inexact,
+            underflow: flags.underflow || (inexact && self.is_subnormal()),
+            ..flags
+        };
+        (x, flags)
+    }
+
+    pub fn round_to_integral(&self, mode: RoundingMode) -> Self {
+        self.round_to_integral_with_flags(mode).0
+    }
+
+    pub fn round_to_integral_with_flags(&self, mode: RoundingMode) -> (Self, Flags) {
+        // same value as the exact version, but inexact is
+        // never signaled (invalid still is, for snans)
+        let (x, flags) = self.integral(mode);
+        (
+            x,
+            Flags {
+                inexact: false,
+                ..flags
+            },
+        )
+    }
+
+    pub fn round_to_integral_exact(&self, mode: RoundingMode) -> Self {
+        self.round_to_integral_exact_with_flags(mode).0
+    }
+
+    pub fn round_to_integral_exact_with_flags(&self, mode: RoundingMode) -> (Self, Flags) {
+        self.integral(mode)
+    }
+
+    pub fn scaleb(&self, n: i32, mode: RoundingMode) -> Self {
+        self.scaleb_with_flags(n, mode).0
+    }
+
+    pub fn scaleb_with_flags(&self, n: i32, mode: RoundingMode) -> (Self, Flags) {
+        if self.is_nan() {
+            return self.propagate_nan(self);
+        }
+        if self.is_infinite() {
+            return (*self, Flags::default());
+        }
+        // the coefficient stays as it is unless the new
+        // exponent is out of range, in which case it's
+        // rounded like any other result
+        let (sign, c, e) = self.decode();
+        let exponent = (e as i64 + n as i64).clamp(-1_000_000_000, 1_000_000_000) as i32;
+        d32::finalize(sign, c as u128, exponent, mode)
+    }
+
+    pub fn logb(&self) -> Self {
+        self.logb_with_flags().0
+    }
+
+    pub fn logb_with_flags(&self) -> (Self, Flags) {
+        if self.is_nan() {
+            return self.propagate_nan(self);
+        }
+        if self.is_infinite() {
+            return (d32::infinity(false), Flags::default());
+        }
+        if self.is_zero() {
+            let flags = Flags {
+                div_by_zero: true,
+                ..Flags::default()
+            };
+            return (d32::infinity(true), flags);
+        }
+        // the exponent of the leading digit, which is never
+        // more than three digits and so always exact
+        let (_, _, e) = self.decode();
+        let adjusted = e + self.digits() as i32 - 1;
+        (
+            d32::encode(adjusted < 0, adjusted.unsigned_abs() as u64, 0),
+            Flags::default(),
+        )
+    }
+
+    pub fn next_up(&self) -> Self {
+        if self.is_nan() {
+            return self.quieted();
+        }
+        if self.is_infinite() {
+            return if self.is_sign_minus() {
+                d32::encode(true, MAX_COEFFICIENT, QMAX)
+            } else {
+                *self
+            };
+        }
+        if self.is_zero() {
+            return d32::encode(false, 1, QMIN);
+        }
+        // spread the coefficient out to all seven digits (or
+        // as far as qmin allows) so the last one is the
+        // smallest step there is at this magnitude
+        let (sign, mut c, mut e) = self.decode();
+        while c <= MAX_COEFFICIENT / 10 && e > QMIN {
+            c *= 10;
+            e -= 1;
+        }
+        if !sign {
+            c += 1;
+            if c > MAX_COEFFICIENT {
+                c /= 10;
+                e += 1;
+                if e > QMAX {
+                    return d32::infinity(false);
+                }
+            }
+        } else {
+            c -= 1;
+            // stepping down past a power of ten means the
+            // next digits over are all nines
+            if c <= MAX_COEFFICIENT / 10 && e > QMIN {
+                c = MAX_COEFFICIENT;
+                e -= 1;
+            }
+        }
+        d32::encode(sign, c, e)
+    }
+
+    pub fn next_down(&self) -> Self {
+        if self.is_nan() {
+            return self.quieted();
+        }
+        self.negate().next_up().negate()
+    }
+
+    pub fn next_toward(&self, y: &d32) -> Self {
+        // one step toward y, or no step if they're already
+        // the same number (which keeps self's own exponent
+        // and sign, zeros included)
+        match self.compare_value(y) {
+            None => self.propagate_nan(y).0,
+            Some(core::cmp::Ordering::Less) => self.next_up(),
+            Some(core::cmp::Ordering::Greater) => self.next_down(),
+            Some(core::cmp::Ordering::Equal) => *self,
+        }
+    }
+
+    pub fn minimum(&self, y: &d32) -> Self {
+        self.minimum_with_flags(y).0
+    }
+
+    pub fn minimum_with_flags(&self, y: &d32) -> (Self, Flags) {
+        // any nan at all wins; numeric ties (signed zeros,
+        // members of a cohort) go by total order, so -0
+        // comes before +0
+        if self.is_nan() || y.is_nan() {
+            return self.propagate_nan(y);
+        }
+        let first = match self.compare_value(y) {
+            Some(core::cmp::Ordering::Less) => true,
+            Some(core::cmp::Ordering::Greater) => false,
+            _ => self.total_order(y),
+        };
+        (if first { *self } else { *y }, Flags::default())
+    }
+
+    pub fn maximum(&self, y: &d32) -> Self {
+        self.maximum_with_flags(y).0
+    }
+
+    pub fn maximum_with_flags(&self, y: &d32) -> (Self, Flags) {
+        if self.is_nan() || y.is_nan() {
+            return self.propagate_nan(y);
+        }
+        let first = match self.compare_value(y) {
+            Some(core::cmp::Ordering::Greater) => true,
+            Some(core::cmp::Ordering::Less) => false,
+            _ => y.total_order(self),
+        };
+        (if first { *self } else { *y }, Flags::default())
+    }
+
+    /// Like `minimum`, except that a nan only comes out when
+    /// both operands are nans; a single nan, signaling or
+    /// not, is passed over for the number (though a
+    /// signaling one still raises invalid).
+    pub fn minimum_number(&self, y: &d32) -> Self {
+        self.minimum_number_with_flags(y).0
+    }
+
+    pub fn minimum_number_with_flags(&self, y: &d32) -> (Self, Flags) {
+        if self.is_nan() != y.is_nan() {
+            return self.number_over_nan(y);
+        }
+        self.minimum_with_flags(y)
+    }
+
+    /// Like `maximum`, except that a nan only comes out when
+    /// both operands are nans; a single nan, signaling or
+    /// not, is passed over for the number (though a
+    /// signaling one still raises invalid).
+    pub fn maximum_number(&self, y: &d32) -> Self {
+        self.maximum_number_with_flags(y).0
+    }
+
+    pub fn maximum_number_with_flags(&self, y: &d32) -> (Self, Flags) {
+        if self.is_nan() != y.is_nan() {
+            return self.number_over_nan(y);
+        }
+        self.maximum_with_flags(y)
+    }
+
+    pub fn minimum_magnitude(&self, y: &d32) -> Self {
+        self.minimum_magnitude_with_flags(y).0
+    }
+
+    pub fn minimum_magnitude_with_flags(&self, y: &d32) -> (Self, Flags) {
+        // by absolute value, unless those are equal, in
+        // which case it's the plain signed minimum
+        match self.abs().compare_value(&y.abs()) {
+            Some(core::cmp::Ordering::Less) => (*self, Flags::default()),
+            Some(core::cmp::Ordering::Greater) => (*y, Flags::default()),
+            _ => self.minimum_with_flags(y),
+        }
+    }
+
+    pub fn maximum_magnitude(&self, y: &d32) -> Self {
+        self.maximum_magnitude_with_flags(y).0
+    }
+
+    pub fn maximum_magnitude_with_flags(&self, y: &d32) -> (Self, Flags) {
+        match self.abs().compare_value(&y.abs()) {
+            Some(core::cmp::Ordering::Greater) => (*self, Flags::default()),
+            Some(core::cmp::Ordering::Less) => (*y, Flags::default()),
+            _ => self.maximum_with_flags(y),
+        }
+    }
+
+    pub fn minimum_magnitude_number(&self, y: &d32) -> Self {
+        self.minimum_magnitude_number_with_flags(y).0
+    }
+
+    pub fn minimum_magnitude_number_with_flags(&self, y: &d32) -> (Self, Flags) {
+        if self.is_nan() != y.is_nan() {
+            return self.number_over_nan(y);
+        }
+        self.minimum_magnitude_with_flags(y)
+    }
+
+    pub fn maximum_magnitude_number(&self, y: &d32) -> Self {
+        self.maximum_magnitude_number_with_flags(y).0
+    }
+
+    pub fn maximum_magnitude_number_with_flags(&self, y: &d32) -> (Self, Flags) {
+        if self.is_nan() != y.is_nan() {
+            return self.number_over_nan(y);
+        }
+        self.maximum_magnitude_with_flags(y)
+    }
+
+    /// Bounds `self` to `min..=max` by numeric comparison (as
+    /// in `PartialOrd`, not `total_order`): `self` comes back
+    /// unchanged unless it's strictly below `min` or above
+    /// `max`, so -0 within 0..=1 stays -0, and 1.0 within
+    /// 1..=2 stays 1.0. Infinite bounds are fine; a nan
+    /// anywhere comes back quieted.
+    pub fn clamp(&self, min: &d32, max: &d32) -> Self {
+        if self.is_nan() || min.is_nan() || max.is_nan() {
+            let operands = [*self, *min, *max];
+            let nan = operands
+                .iter()
+                .find(|x| x.is_signaling())
+                .or_else(|| operands.iter().find(|x| x.is_nan()))
+                .expect("one of them is a nan");
+            return nan.quieted();
+        }
+        debug_assert!(min <= max, "clamp with min > max");
+        if self < min {
+            *min
+        } else if self > max {
+            *max
+        } else {
+            *self
+        }
+    }
+
+    pub fn sqrt(&self, mode: RoundingMode) -> Self {
+        self.sqrt_with_flags(mode).0
+    }
+
+    pub fn sqrt_with_flags(&self, mode: RoundingMode) -> (Self, Flags) {
+        if self.is_nan() {
+            return self.propagate_nan(self);
+        }
+        // the ideal exponent is half the operand's, rounded
+        // down, and zeros keep their sign
+        let (sign, c, e) = self.decode();
+        let ideal = e.div_euclid(2);
+        if c == 0 && self.is_finite() {
+            return d32::finalize(sign, 0, ideal, mode);
+        }
+        if sign {
+            return d32::invalid();
+        }
+        if self.is_infinite() {
+            return (d32::infinity(false), Flags::default());
+        }
+
+        // scale by an even power of ten (plus one more if
+        // the exponent is odd) so the root has a couple of
+        // digits more than the precision
+        let (radicand, exponent) = if e % 2 == 0 {
+            (c as u128 * pow10(16), e - 16)
+        } else {
+            (c as u128 * pow10(17), e - 17)
+        };
+        let mut root = isqrt(radicand);
+        let mut exponent = exponent / 2;
+        if root * root != radicand {
+            // irrational, so it's just a matter of a sticky
+            // digit to push rounding the right way
+            root = root * 10 + 1;
+            exponent -= 1;
+        } else {
+            while exponent < ideal && root.is_multiple_of(10) {
+                root /= 10;
+                exponent += 1;
+            }
+        }
+        d32::finalize(false, root, exponent, mode)
+    }
+
+    pub fn exp(&self, mode: RoundingMode) -> Self {
+        self.exp_with_flags(mode).0
+    }
+
+    pub fn exp_with_flags(&self, mode: RoundingMode) -> (Self, Flags) {
+        // the only exact case is exp(0) = 1; the rest is worked
+        // out to well past the precision and correctly rounded,
+        // unless it falls too close to call, where it's still
+        // faithful (less than an ulp off)
+        if self.is_nan() {
+            return self.propagate_nan(self);
+        }
+        if self.is_infinite() {
+            if self.is_sign_minus() {
+                return (d32::ZERO, Flags::default());
+            }
+            return (d32::INFINITY, Flags::default());
+        }
+        let (sign, c, e) = self.decode();
+        if c == 0 {
+            return (d32::ONE, Flags::default());
+        }
+        let adjusted = e + digit_count(c as u128) as i32 - 1;
+        if adjusted < -20 {
+            // 1 + x, give or take x^2, is just to one side of one
+            let nudged = if sign { pow10(34) - 1 } else { pow10(34) + 1 };
+            return d32::finalize(false, nudged, -34, mode);
+        }
+        if adjusted > 2 {
+            // a thousand or more is far beyond the range either way
+            if sign {
+                return d32::finalize(false, 1, -1000, mode);
+            }
+            return d32::overflow(false, mode);
+        }
+        let x = c as i128 * pow10((e + 32) as u32) as i128;
+        d32::exp_approx(if sign { -x } else { x }, FIXED_ERROR, false, mode)
+    }
+
+    pub fn ln(&self, mode: RoundingMode) -> Self {
+        self.ln_with_flags(mode).0
+    }
+
+    pub fn ln_with_flags(&self, mode: RoundingMode) -> (Self, Flags) {
+        // rounded the same way as exp, and exact only at one
+        if let Some(special) = self.log_special() {
+            return special;
+        }
+        let (_, c, e) = self.decode();
+        let x = fixed_ln(c as u128, e);
+        d32::round_approx(x < 0, x.unsigned_abs(), -32, FIXED_ERROR, mode)
+    }
+
+    pub fn log10(&self, mode: RoundingMode) -> Self {
+        self.log10_with_flags(mode).0
+    }
+
+    pub fn log10_with_flags(&self, mode: RoundingMode) -> (Self, Flags) {
+        // powers of ten give their exponent exactly, however
+        // they're written; anything else is irrational
+        if let Some(special) = self.log_special() {
+            return special;
+        }
+        let (_, mut c, mut e) = self.decode();
+        while c % 10 == 0 {
+            c /= 10;
+            e += 1;
+        }
+        if c == 1 {
+            return d32::finalize(e < 0, e.unsigned_abs() as u128, 0, mode);
+        }
+        let x = fixed_div(fixed_ln(c as u128, e), FIXED_LN10);
+        d32::round_approx(x < 0, x.unsigned_abs(), -32, FIXED_ERROR, mode)
+    }
+
+    pub fn pown(&self, n: i32, mode: RoundingMode) -> Self {
+        self.pown_with_flags(n, mode).0
+    }
+
+    pub fn pown_with_flags(&self, n: i32, mode: RoundingMode) -> (Self, Flags) {
+        // x^0 is one for anything but a signaling nan; when the
+        // exact power fits in 128 bits it's rounded just once,
+        // and otherwise it goes by way of exp and ln like pow
+        if self.is_signaling() {
+            return self.propagate_nan(self);
+        }
+        if n == 0 {
+            return (d32::ONE, Flags::default());
+        }
+        if self.is_nan() {
+            return self.propagate_nan(self);
+        }
+        let (sign, c, e) = self.decode();
+        let sign = sign && n % 2 != 0;
+        if self.is_infinite() || c == 0 {
+            // zeros and infinities are reciprocals of each other
+            if self.is_infinite() != (n > 0) {
+                return (d32::encode(sign, 0, 0), Flags::default());
+            }
+            let flags = Flags {
+                div_by_zero: c == 0 && self.is_finite(),
+                ..Flags::default()
+            };
+            return (d32::infinity(sign), flags);
+        }
+        d32::power(c as u128, e, n, sign, mode)
+    }
+
+    pub fn compound(&self, n: i32, mode: RoundingMode) -> Self {
+        self.compound_with_flags(n, mode).0
+    }
+
+    pub fn compound_with_flags(&self, n: i32, mode: RoundingMode) -> (Self, Flags) {
+        // (1 + x)^n, with 1 + x worked out exactly (to 38 digits
+        // if need be) rather than rounded first, so that it's
+        // rounded only once, and exactly as pown would where
+        // it can be; below -1 it's invalid
+        if self.is_signaling() {
+            return self.propagate_nan(self);
+        }
+        if self.is_nan() {
+            if n == 0 {
+                return (d32::ONE, Flags::default());
+            }
+            return self.propagate_nan(self);
+        }
+        let minus_one = d32::ONE.negate();
+        if *self < minus_one {
+            return d32::invalid();
+        }
+        if n == 0 {
+            return (d32::ONE, Flags::default());
+        }
+        if *self == minus_one || self.is_infinite() {
+            // 1 + x is zero or infinite
+            if self.is_infinite() != (n > 0) {
+                return (d32::ZERO, Flags::default());
+            }
+            let flags = Flags {
+                div_by_zero: self.is_finite(),
+                ..Flags::default()
+            };
+            return (d32::INFINITY, flags);
+        }
+        let (sign, c, e) = self.decode();
+        if c == 0 {
+            return (d32::ONE, Flags::default());
+        }
+        if e + (digit_count(c as u128) as i32) - 1 < -20 {
+            // (1 + x)^n is within 1e-10 or so of one, on the
+            // side n x is
+            let t = if sign != (n < 0) { -1 } else { 1 };
+            return d32::exp_approx(t, 0, false, mode);
+        }
+        let (base, exponent) = if e < 0 {
+            let one = pow10(e.unsigned_abs());
+            let base = if sign {
+                one - c as u128
+            } else {
+                one + c as u128
+            };
+            (base, e)
+        } else if e <= 22 {
+            (c as u128 * pow10(e as u32) + 1, 0)
+        } else {
+            // one is beyond the 29th digit, so only needs to be
+            // a sticky digit somewhere past x; that keeps 1 + x
+            // small enough for pown's exact reciprocal
+            (c as u128 * pow10(22) + 1, e - 22)
+        };
+        d32::power(base, exponent, n, false, mode)
+    }
+
+    pub fn rootn(&self, n: i32, mode: RoundingMode) -> Self {
+        self.rootn_with_flags(n, mode).0
+    }
+
+    pub fn rootn_with_flags(&self, n: i32, mode: RoundingMode) -> (Self, Flags) {
+        // x^(1/n); a negative x needs an odd n, and exact roots
+        // come back exactly, with the exponent as near e/n as
+        // the coefficient allows
+        if self.is_nan() {
+            return self.propagate_nan(self);
+        }
+        let odd = n % 2 != 0;
+        if n == 0 || (self.is_sign_minus() && !odd && !self.is_zero()) {
+            return d32::invalid();
+        }
+        let (sign, c, e) = self.decode();
+        let sign = sign && odd;
+        if self.is_infinite() || c == 0 {
+            if self.is_infinite() != (n > 0) {
+                return (d32::encode(sign, 0, 0), Flags::default());
+            }
+            let flags = Flags {
+                div_by_zero: c == 0 && self.is_finite(),
+                ..Flags::default()
+            };
+            return (d32::infinity(sign), flags);
+        }
+        let degree = n.unsigned_abs();
+        if let Some((root, exponent)) = d32::exact_root(c, e, degree) {
+            if n > 0 {
+                return d32::finalize(sign, root, exponent, mode);
+            }
+            return d32::power(root, exponent, -1, sign, mode);
+        }
+        let t = fixed_ln(c as u128, e) / n as i128;
+        d32::exp_approx(t, FIXED_ERROR, sign, mode)
+    }
+
+    /// The standard's `pow`, rather than `powr`: it's defined
+    /// for negative bases raised to integers, and follows C in
+    /// x^0 = 1 and 1^y = 1 for any x and y (nans included, but
+    /// not signaling ones). A negative base with a non-integer
+    /// exponent is invalid. Integer exponents that fit an `i32`
+    /// go to `pown`, so they're exact where they can be, as are
+    /// short fractional exponents whose root of `x` is exact
+    /// (`pow(4, 0.5)` is exactly 2); other results are rounded
+    /// like `exp`.
+    pub fn pow(&self, y: &d32, mode: RoundingMode) -> Self {
+        self.pow_with_flags(y, mode).0
+    }
+
+    pub fn pow_with_flags(&self, y: &d32, mode: RoundingMode) -> (Self, Flags) {
+        if y.is_zero() && !self.is_signaling() {
+            return (d32::ONE, Flags::default());
+        }
+        if *self == d32::ONE && !y.is_signaling() {
+            return (d32::ONE, Flags::default());
+        }
+        if self.is_nan() || y.is_nan() {
+            return self.propagate_nan(y);
+        }
+        let parity = y.integer_parity();
+        if self.is_sign_minus() && self.is_finite() && !self.is_zero() {
+            if y.is_infinite() && *self == d32::ONE.negate() {
+                return (d32::ONE, Flags::default());
+            }
+            if parity.is_none() && y.is_finite() {
+                return d32::invalid();
+            }
+        }
+        let sign = self.is_sign_minus() && parity == Some(true);
+        if y.is_infinite() {
+            // big or small depending on which side of one |x| is
+            let above_one = self.abs() > d32::ONE;
+            if above_one != y.is_sign_minus() {
+                return (d32::INFINITY, Flags::default());
+            }
+            return (d32::ZERO, Flags::default());
+        }
+        if self.is_zero() || self.is_infinite() {
+            // as in pown, just without the integer exponent
+            if self.is_infinite() == y.is_sign_minus() {
+                return (d32::encode(sign, 0, 0), Flags::default());
+            }
+            let flags = Flags {
+                div_by_zero: self.is_zero(),
+                ..Flags::default()
+            };
+            return (d32::infinity(sign), flags);
+        }
+        if parity.is_some() {
+            if let Ok(n) = i32::try_from(*y) {
+                return self.pown_with_flags(n, mode);
+            }
+        }
+        let (_, c, e) = self.decode();
+        let (y_sign, y_coefficient, y_exponent) = y.decode();
+        // a short y is a fraction, and where x has an exact root
+        // to match, the result's an integer power of that root
+        if let Some((root, exponent, m)) = d32::fractional_root(c, e, y_coefficient, y_exponent) {
+            let n = if y_sign { -m } else { m };
+            return d32::power(root, exponent, n, sign, mode);
+        }
+        d32::pow_approx(
+            c as u128,
+            e,
+            (y_sign, y_coefficient, y_exponent),
+            sign,
+            mode,
+        )
+    }
+
+    pub fn hypot(&self, y: &d32, mode: RoundingMode) -> Self {
+        self.hypot_with_flags(y, mode).0
+    }
+
+    pub fn hypot_with_flags(&self, y: &d32, mode: RoundingMode) -> (Self, Flags) {
+        // an infinity wins even over a quiet nan, since the
+        // result is infinite whatever the nan might have been
+        if self.is_signaling() || y.is_signaling() {
+            return self.propagate_nan(y);
+        }
+        if self.is_infinite() || y.is_infinite() {
+            return (d32::INFINITY, Flags::default());
+        }
+        if self.is_nan() || y.is_nan() {
+            return self.propagate_nan(y);
+        }
+        let (_, cx, ex) = self.decode();
+        let (_, cy, ey) = y.decode();
+        let ideal = ex.min(ey);
+        if cx == 0 || cy == 0 {
+            let (c, e) = if cx == 0 { (cy, ey) } else { (cx, ex) };
+            return d32::finalize(false, c as u128, if c == 0 { ideal } else { e }, mode);
+        }
+
+        // the squares are only ever taken of the coefficients,
+        // with the exponents kept aside, so nothing overflows
+        // or underflows on the way; padding the coefficients
+        // out to the full precision lines up the exponents
+        // with the magnitudes
+        let pad = |c: u64, e: i32| {
+            let shift = PRECISION - digit_count(c as u128);
+            (c as u128 * pow10(shift), e - shift as i32)
+        };
+        let (mut big, mut small) = (pad(cx, ex), pad(cy, ey));
+        if small.1 > big.1 {
+            core::mem::swap(&mut big, &mut small);
+        }
+        let shift = big.1 - small.1;
+        if shift > 12 {
+            // the smaller square is less than 1e-22 of the
+            // bigger, and only nudges it up
+            return d32::finalize(false, big.0 * 10 + 1, big.1 - 1, mode);
+        }
+        let b = big.0 * pow10(shift as u32);
+        let mut radicand = b * b + small.0 * small.0;
+        let mut exponent = small.1;
+        while radicand < pow10(36) {
+            radicand *= 100;
+            exponent -= 1;
+        }
+        let mut root = isqrt(radicand);
+        if root * root != radicand {
+            root = root * 10 + 1;
+            exponent -= 1;
+        } else {
+            while exponent < ideal && root.is_multiple_of(10) {
+                root /= 10;
+                exponent += 1;
+            }
+        }
+        d32::finalize(false, root, exponent, mode)
+    }
+
+    pub fn to_i32(&self, mode: RoundingMode) -> Result<i32, Flags> {
+        // the decimal value gets rounded, not the coefficient
+        // truncated; nans, infinities and anything out of
+        // range are invalid
+        let invalid = Flags {
+            invalid: true,
+            ..Flags::default()
+        };
+        let (sign, magnitude, _) = self.round_integral(mode).ok_or(invalid)?;
+        let value = if sign {
+            -(magnitude as i128)
+        } else {
+            magnitude as i128
+        };
+        i32::try_from(value).map_err(|_| invalid)
+    }
+
+    pub fn to_i64_exact(&self) -> Result<i64, Flags> {
+        // only exact integers make it through: anything with
+        // a fractional part is inexact, and the rest of the
+        // failures are invalid just like with to_i32
+        let invalid = Flags {
+            invalid: true,
+            ..Flags::default()
+        };
+        let (sign, magnitude, inexact) = self
+            .round_integral(RoundingMode::TowardZero)
+            .ok_or(invalid)?;
+        let value = if sign {
+            -(magnitude as i128)
+        } else {
+            magnitude as i128
+        };
+        let value = i64::try_from(value).map_err(|_| invalid)?;
+        if inexact {
+            return Err(Flags {
+                inexact: true,
+                ..Flags::default()
+            });
+        }
+        Ok(value)
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        // the standard library's parser already rounds a
+        // decimal string to the nearest f64, signed zeros
+        // included
+        if self.is_nan() {
+            return if self.is_sign_minus() {
+                -f64::NAN
+            } else {
+                f64::NAN
+            };
+        }
+        if self.is_infinite() {
+            return if self.is_sign_minus() {
+                f64::NEG_INFINITY
+            } else {
+                f64::INFINITY
+            };
+        }
+        let (sign, coefficient, exponent) = self.decode();
+        let sign = if sign { "-" } else { "" };
+        let mut buf = StackString::default();
+        write!(buf, "{}{}e{}", sign, coefficient, exponent).expect("it's long enough");
+        buf.as_str()
+            .parse()
+            .expect("a decimal string always parses")
+    }
+
+    pub fn to_f32(&self) -> f32 {
+        // parsed straight to f32 rather than going through
+        // to_f64, which would round twice
+        if self.is_nan() {
+            return if self.is_sign_minus() {
+                -f32::NAN
+            } else {
+                f32::NAN
+            };
+        }
+        if self.is_infinite() {
+            return if self.is_sign_minus() {
+                f32::NEG_INFINITY
+            } else {
+                f32::INFINITY
+            };
+        }
+        let (sign, coefficient, exponent) = self.decode();
+        let sign = if sign { "-" } else { "" };
+        let mut buf = StackString::default();
+        write!(buf, "{}{}e{}", sign, coefficient, exponent).expect("it's long enough");
+        buf.as_str()
+            .parse()
+            .expect("a decimal string always parses")
+    }
+
+    pub fn compare(&self, y: &d32) -> Comparison {
+        // quiet, so nans are just unordered
+        match self.compare_value(y) {
+            Some(core::cmp::Ordering::Less) => Comparison::Less,
+            Some(core::cmp::Ordering::Equal) => Comparison::Equal,
+            Some(core::cmp::Ordering::Greater) => Comparison::Greater,
+            None => Comparison::Unordered,
+        }
+    }
+
+    pub fn compare_signaling_equal(&self, y: &d32) -> (bool, Flags) {
+        let (order, flags) = self.compare_signaling(y);
+        (order == Some(core::cmp::Ordering::Equal), flags)
+    }
+
+    pub fn compare_signaling_greater(&self, y: &d32) -> (bool, Flags) {
+        let (order, flags) = self.compare_signaling(y);
+        (order == Some(core::cmp::Ordering::Greater), flags)
+    }
+
+    pub fn compare_signaling_greater_equal(&self, y: &d32) -> (bool, Flags) {
+        let (order, flags) = self.compare_signaling(y);
+        (order.is_some_and(|o| o != core::cmp::Ordering::Less), flags)
+    }
+
+    pub fn compare_signaling_less(&self, y: &d32) -> (bool, Flags) {
+        let (order, flags) = self.compare_signaling(y);
+        (order == Some(core::cmp::Ordering::Less), flags)
+    }
+
+    pub fn compare_signaling_less_equal(&self, y: &d32) -> (bool, Flags) {
+        let (order, flags) = self.compare_signaling(y);
+        (
+            order.is_some_and(|o| o != core::cmp::Ordering::Greater),
+            flags,
+        )
+    }
+
+    /* ********************************************** *
+     *              QUIET-COMP FUNCTIONS              *
+     * ********************************************** */
+
+    // the copy family: bit operations that never signal, even
+    // on signaling nans, which stay signaling. only the sign
+    // bit is touched, so noncanonical encodings survive too
+
+    pub fn copy(&self) -> Self {
+        *self
+    }
+
+    pub fn negate(&self) -> Self {
+        d32(self.0 ^ 0x80000000)
+    }
+
+    pub fn abs(&self) -> Self {
+        d32(self.0 & 0x7fffffff)
+    }
+
+    // the standard's names for negate and abs
+    pub fn copy_negate(&self) -> Self {
+        self.negate()
+    }
+
+    pub fn copy_abs(&self) -> Self {
+        self.abs()
+    }
+
+    pub fn signum(&self) -> Self {
+        // zeros keep their sign rather than becoming -1
+        if self.is_nan() {
+            self.quieted()
+        } else if self.is_zero() {
+            d32::ZERO.copy_sign(self)
+        } else {
+            d32::ONE.copy_sign(self)
+        }
+    }
+
+    pub fn copy_sign(&self, y: &d32) -> Self {
+        // nothing but the sign bit changes hands, so nans keep
+        // their payload and stay signaling if they were, and
+        // a nan y lends its sign like anything else
+        d32(self.abs().0 | (y.0 & 0x80000000))
+    }
+
+    pub fn canonicalize(&self) -> Self {
+        // decode whatever's there and encode it again,
+        // which picks the canonical declets and leaves the
+        // unused bits of specials clear
+        if self.is_nan() {
+            d32::nan_with_payload(
+                self.is_sign_minus(),
+                self.is_signaling(),
+                self.nan_payload(),
+            )
+        } else if self.is_infinite() {
+            d32::infinity(self.is_sign_minus())
+        } else {
+            d32::encode(
+                self.is_sign_minus(),
+                self.significand(),
+                self.exponent() as i32 - BIAS,
+            )
+        }
+    }
+
+    pub fn encode_binary(&self) -> Self {
+        // bid shares the sign and special-value bits with
+        // dpd, but stores the coefficient (or nan payload)
+        // as a plain binary integer
+        if self.is_nan() {
+            return d32((self.0 & 0xfe000000) | self.nan_payload());
+        }
+        if self.is_infinite() {
+            return d32::infinity(self.is_sign_minus());
+        }
+        let (sign, coefficient, exponent) = self.decode();
+        let sign = if sign { 0x80000000 } else { 0 };
+        let biased = (exponent + BIAS) as u32;
+        let coefficient = coefficient as u32;
+        // coefficients that fit in 23 bits take the first
+        // form; the rest need the 11 prefix and the
+        // implicit 100 leading bits
+        if coefficient < 0x00800000 {
+            d32(sign | biased << 23 | coefficient)
+        } else {
+            d32(sign | 0x60000000 | biased << 21 | (coefficient & 0x001fffff))
+        }
+    }
+
+    pub fn decode_binary(&self) -> Self {
+        // the reverse of encode_binary; out-of-range bid
+        // coefficients and payloads are noncanonical, and
+        // read as zero
+        if self.is_nan() {
+            let payload = self.0 & 0x000fffff;
+            let payload = if payload > 999999 { 0 } else { payload };
+            return d32::nan_with_payload(self.is_sign_minus(), self.is_signaling(), payload);
+        }
+        if self.is_infinite() {
+            return d32::infinity(self.is_sign_minus());
+        }
+        let (coefficient, biased) = if self.0 & 0x60000000 != 0x60000000 {
+            (self.0 & 0x007fffff, (self.0 >> 23) & 0xff)
+        } else {
+            ((self.0 & 0x001fffff) | 0x00800000, (self.0 >> 21) & 0xff)
+        };
+        let coefficient = if coefficient as u64 > MAX_COEFFICIENT {
+            0
+        } else {
+            coefficient
+        };
+        d32::encode(
+            self.is_sign_minus(),
+            coefficient as u64,
+            biased as i32 - BIAS,
+        )
+    }
+
+    /* ********************************************** *
+     *               NON-COMP FUNCTIONS               *
+     * ********************************************** */
+
+    pub const fn is_754_version_1985() -> bool {
+        // 754-1985 was binary only; decimal formats came
+        // in with the 2008 revision
+        false
+    }
+
+    pub const fn is_754_version_2008() -> bool {
+        true
+    }
+
+    pub const fn is_754_version_2019() -> bool {
+        true
+    }
+
+    pub fn class(&self) -> Class {
+        if self.is_signaling() {
+            Class::SignalingNaN
+        } else if self.is_quiet() {
+            Class::QuietNaN
+        } else if self.is_sign_minus() {
+            if self.is_infinite() {
+                Class::NegativeInf
+            } else if self.is_normal() {
+                Class::NegativeNormal
+            } else if self.is_subnormal() {
+                Class::NegativeSubnormal
+            } else {
+                Class::NegativeZero
+            }
+        } else if self.is_infinite() {
+            Class::PositiveInf
+        } else if self.is_normal() {
+            Class::PositiveNormal
+        } else if self.is_subnormal() {
+            Class::PositiveSubnormal
+        } else {
+            Class::PositiveZero
+        }
+    }
+
+    /// Classifies each of `xs` into the same place in `out`,
+    /// exactly as `class` would.
+    ///
+    /// Panics if the two slices differ in length.
+    pub fn classify_slice(xs: &[d32], out: &mut [Class]) {
+        assert_eq!(xs.len(), out.len(), "classify_slice needs equal lengths");
+        for (x, class) in xs.iter().zip(out.iter_mut()) {
+            *class = class_of_bits(x.0);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn is_nan_mask(xs: &[d32]) -> Vec<bool> {
+        // the same mask and compare as is_nan, with nothing
+        // to branch on
+        xs.iter().map(|x| x.0 & 0x7c000000 == 0x7c000000).collect()
+    }
+
+    pub fn class_number(&self) -> i32 {
+        // numbered in the order the standard lists them,
+        // which puts signaling nans ahead of quiet ones
+        match self.class() {
+            Class::SignalingNaN => 0,
+            Class::QuietNaN => 1,
+            Class::NegativeInf => 2,
+            Class::NegativeNormal => 3,
+            Class::NegativeSubnormal => 4,
+            Class::NegativeZero => 5,
+            Class::PositiveZero => 6,
+            Class::PositiveSubnormal => 7,
+            Class::PositiveNormal => 8,
+            Class::PositiveInf => 9,
+        }
+    }
+
+    pub fn category(&self) -> core::num::FpCategory {
+        use core::num::FpCategory;
+        match self.class() {
+            Class::QuietNaN | Class::SignalingNaN => FpCategory::Nan,
+            Class::NegativeInf | Class::PositiveInf => FpCategory::Infinite,
+            Class::NegativeNormal | Class::PositiveNormal => FpCategory::Normal,
+            Class::NegativeSubnormal | Class::PositiveSubnormal => FpCategory::Subnormal,
+            Class::NegativeZero | Class::PositiveZero => FpCategory::Zero,
+        }
+    }
+
+    pub fn is_sign_minus(&self) -> bool {
+        self.0 & 0x80000000 == 0x80000000
+    }
+
+    pub fn is_normal(&self) -> bool {
+        self.is_finite() && !self.is_zero() && !self.is_subnormal()
+    }
+
+    pub fn is_finite(&self) -> bool {
+        !(self.is_infinite() || self.is_nan())
+    }
+
+    pub fn is_zero(&self) -> bool {
+        // need combination field to not indicate inf or nan,
+        // and need significand to be zero; noncanonical
+        // declets all decode to something in the 800s or
+        // 900s, so never count
+        self.is_finite() && self.significand() == 0
+    }
+
+    pub fn is_subnormal(&self) -> bool {
+        // nonzero, but with its leading digit (i.e. the
+        // adjusted exponent) below emin
+        if !self.is_finite() || self.is_zero() {
+            return false;
+        }
+        let (_, _, exponent) = self.decode();
+        exponent + self.digits() as i32 - 1 < EMIN
+    }
+
+    pub fn is_integer(&self) -> bool {
+        // any finite value whose fraction digits are all zeros,
+        // whatever its exponent says
+        self.integer_parity().is_some()
+    }
+
+    pub fn is_infinite(&self) -> bool {
+        // G0 through G4 are 11110; 11111 is a nan, and the
+        // rest of the field doesn't matter
+        self.0 & 0x7c000000 == 0x78000000
+    }
+
+    pub fn is_nan(&self) -> bool {
+        self.0 & 0x7c000000 == 0x7c000000
+    }
+
+    pub fn is_signaling(&self) -> bool {
+        self.0 & 0x7e000000 == 0x7e000000
+    }
+
+    pub fn payload(&self) -> Option<u32> {
+        if self.is_nan() {
+            Some(self.nan_payload())
+        } else {
+            None
+        }
+    }
+
+    pub fn is_canonical(&self) -> bool {
+        // the leading digit can't be spelled wrong, since each
+        // combination form covers exactly its own digits; it's
+        // the declets that have 24 patterns (three apiece for
+        // the eight values with two or more digits over 7)
+        // that decode fine but aren't what we'd ever encode
+        let declets = [(self.0 >> 10) & 0x3ff, self.0 & 0x3ff]
+            .iter()
+            .all(|&d| encode_declet(decode_declet(d as u16)) == d as u16);
+        (self.is_nan() && self.0 & 0x7df00000 == 0x7c000000 && declets)
+            || (self.is_infinite() && self.0 & 0x7fffffff == 0x78000000)
+            || (self.is_finite() && declets)
+    }
+
+    pub fn radix(&self) -> u32 {
+        10
+    }
+
+    pub fn coefficient(&self) -> Option<u32> {
+        if self.is_finite() {
+            Some(self.significand() as u32)
+        } else {
+            None
+        }
+    }
+
+    pub fn digits(&self) -> u32 {
+        // zero still has the one digit; nans count the
+        // digits of their payload, and infinities just one
+        if self.is_finite() {
+            digit_count(self.significand() as u128)
+        } else if self.is_nan() {
+            digit_count(self.nan_payload() as u128)
+        } else {
+            1
+        }
+    }
+
+    pub fn unbiased_exponent(&self) -> Option<i32> {
+        if self.is_finite() {
+            Some(self.exponent() as i32 - BIAS)
+        } else {
+            None
+        }
+    }
+
+    pub fn total_order(&self, y: &d32) -> bool {
+        // the sign decides alone, nans and zeros included;
+        // otherwise it's the order of the magnitudes, mirrored
+        // for negatives, with each side decoded just the once
+        if self.is_sign_minus() != y.is_sign_minus() {
+            return self.is_sign_minus();
+        }
+        let (a, b) = (self.order_key(), y.order_key());
+        if self.is_sign_minus() {
+            a >= b
+        } else {
+            a <= b
+        }
+    }
+
+    pub fn total_order_mag(&self, y: &d32) -> bool {
+        self.order_key() <= y.order_key()
+    }
+
+    pub fn same_quantum(&self, y: &d32) -> bool {
+        // the exponent field is never noncanonical in dpd (bad
+        // declets only touch the coefficient), so comparing
+        // the decoded exponents is comparing canonical ones
+        (self.is_nan() && y.is_nan())
+            || (self.is_infinite() && y.is_infinite())
+            || (self.is_finite()
+                && y.is_finite()
+                && self.unbiased_exponent() == y.unbiased_exponent())
+    }
+
+    pub fn bit_eq(&self, y: &d32) -> bool {
+        // the same encoding, bit for bit: unlike ==, members of
+        // a cohort differ, as do zeros of either sign, nans
+        // with other payloads, and noncanonical encodings
+        // from their canonical ones
+        self.0 == y.0
+    }
+}
+
+impl PartialEq for d32 {
+    fn eq(&self, other: &d32) -> bool {
+        // compareQuietEqual, so no Eq: nans aren't even
+        // equal to themselves
+        self.compare_value(other) == Some(core::cmp::Ordering::Equal)
+    }
+}
+
+impl core::hash::Hash for d32 {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        // has to agree with PartialEq, so hash the number,
+        // not the encoding: strip a cohort down to its
+        // shortest member, and let every zero hash alike
+        // (as well as every nan, for lack of anything else)
+        if self.is_nan() {
+            0u8.hash(state);
+        } else if self.is_infinite() {
+            1u8.hash(state);
+            self.is_sign_minus().hash(state);
+        } else if self.is_zero() {
+            2u8.hash(state);
+        } else {
+            let (sign, mut c, mut e) = self.decode();
+            while c % 10 == 0 {
+                c /= 10;
+                e += 1;
+            }
+            3u8.hash(state);
+            (sign, c, e).hash(state);
+        }
+    }
+}
+
+impl PartialOrd for d32 {
+    fn partial_cmp(&self, other: &d32) -> Option<core::cmp::Ordering> {
+        // numeric order, not total_order: cohorts and signed
+        // zeros compare equal, and nans are unordered
+        self.compare_value(other)
+    }
+}
+
+impl Ord for TotalOrd {
+    fn cmp(&self, other: &TotalOrd) -> core::cmp::Ordering {
+        use core::cmp::Ordering;
+        match (self.0.total_order(&other.0), other.0.total_order(&self.0)) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Less,
+            _ => Ordering::Greater,
+        }
+    }
+}
+
+impl PartialOrd for TotalOrd {
+    fn partial_cmp(&self, other: &TotalOrd) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for TotalOrd {
+    fn eq(&self, other: &TotalOrd) -> bool {
+        self.cmp(other) == core::cmp::Ordering::Equal
+    }
+}
+
+impl Eq for TotalOrd {}
+
+impl Context {
+    pub fn new(rounding: RoundingMode) -> Self {
+        Context {
+            rounding,
+            flags: Flags::default(),
+        }
+    }
+
+    fn raise(&mut self, (x, flags): (d32, Flags)) -> d32 {
+        // flags are sticky: once raised, they stay that way
+        // until someone clears them
+        self.flags.invalid |= flags.invalid;
+        self.flags.div_by_zero |= flags.div_by_zero;
+        self.flags.overflow |= flags.overflow;
+        self.flags.underflow |= flags.underflow;
+        self.flags.inexact |= flags.inexact;
+        x
+    }
+
+    pub fn add(&mut self, x: &d32, y: &d32) -> d32 {
+        let result = x.add_with_flags(y, self.rounding);
+        self.raise(result)
+    }
+
+    pub fn sub(&mut self, x: &d32, y: &d32) -> d32 {
+        let result = x.subtract_with_flags(y, self.rounding);
+        self.raise(result)
+    }
+
+    pub fn mul(&mut self, x: &d32, y: &d32) -> d32 {
+        let result = x.multiply_with_flags(y, self.rounding);
+        self.raise(result)
+    }
+
+    pub fn div(&mut self, x: &d32, y: &d32) -> d32 {
+        let result = x.divide_with_flags(y, self.rounding);
+        self.raise(result)
+    }
+
+    pub fn fma(&mut self, x: &d32, y: &d32, z: &d32) -> d32 {
+        let result = x.fused_multiply_add_with_flags(y, z, self.rounding);
+        self.raise(result)
+    }
+
+    pub fn sqrt(&mut self, x: &d32) -> d32 {
+        let result = x.sqrt_with_flags(self.rounding);
+        self.raise(result)
+    }
+
+    pub fn remainder(&mut self, x: &d32, y: &d32) -> d32 {
+        let result = x.remainder_with_flags(y);
+        self.raise(result)
+    }
+
+    pub fn quantize(&mut self, x: &d32, y: &d32) -> d32 {
+        let result = x.quantize_with_flags(y, self.rounding);
+        self.raise(result)
+    }
+
+    pub fn scaleb(&mut self, x: &d32, n: i32) -> d32 {
+        let result = x.scaleb_with_flags(n, self.rounding);
+        self.raise(result)
+    }
+
+    pub fn round_to_integral_exact(&mut self, x: &d32) -> d32 {
+        let result = x.round_to_integral_exact_with_flags(self.rounding);
+        self.raise(result)
+    }
+}
+
+impl core::ops::Neg for d32 {
+    type Output = d32;
+
+    fn neg(self) -> d32 {
+        self.negate()
+    }
+}
+
+// the operators round to nearest, ties to even, and drop the
+// flags; the methods are there for anything else
+
+impl core::ops::Add for d32 {
+    type Output = d32;
+
+    fn add(self, y: d32) -> d32 {
+        d32::add(&self, &y, RoundingMode::TiesToEven)
+    }
+}
+
+impl core::ops::Sub for d32 {
+    type Output = d32;
+
+    fn sub(self, y: d32) -> d32 {
+        self.subtract(&y, RoundingMode::TiesToEven)
+    }
+}
+
+impl core::ops::Mul for d32 {
+    type Output = d32;
+
+    fn mul(self, y: d32) -> d32 {
+        self.multiply(&y, RoundingMode::TiesToEven)
+    }
+}
+
+impl core::ops::Div for d32 {
+    type Output = d32;
+
+    fn div(self, y: d32) -> d32 {
+        self.divide(&y, RoundingMode::TiesToEven)
+    }
+}
+
+impl core::ops::AddAssign for d32 {
+    fn add_assign(&mut self, y: d32) {
+        *self = *self + y;
+    }
+}
+
+impl core::ops::SubAssign for d32 {
+    fn sub_assign(&mut self, y: d32) {
+        *self = *self - y;
+    }
+}
+
+impl core::ops::MulAssign for d32 {
+    fn mul_assign(&mut self, y: d32) {
+        *self = *self * y;
+    }
+}
+
+impl core::ops::DivAssign for d32 {
+    fn div_assign(&mut self, y: d32) {
+        *self = *self / y;
+    }
+}
+
+// a left fold, rounding after every step; it's no more
+// accurate than writing the loop out by hand
+
+impl core::iter::Sum for d32 {
+    fn sum<I: Iterator<Item = d32>>(iter: I) -> d32 {
+        iter.fold(d32::ZERO, |acc, x| acc + x)
+    }
+}
+
+impl<'a> core::iter::Sum<&'a d32> for d32 {
+    fn sum<I: Iterator<Item = &'a d32>>(iter: I) -> d32 {
+        iter.copied().sum()
+    }
+}
+
+impl core::iter::Product for d32 {
+    fn product<I: Iterator<Item = d32>>(iter: I) -> d32 {
+        iter.fold(d32::ONE, |acc, x| acc * x)
+    }
+}
+
+impl<'a> core::iter::Product<&'a d32> for d32 {
+    fn product<I: Iterator<Item = &'a d32>>(iter: I) -> d32 {
+        iter.copied().product()
+    }
+}
+
+impl Default for d32 {
+    fn default() -> Self {
+        d32::ZERO
+    }
+}
+
+impl From<i32> for d32 {
+    fn from(value: i32) -> Self {
+        // exact up to 7 digits, with exponent 0; anything
+        // bigger rounds to nearest
+        let magnitude = value.unsigned_abs() as u128;
+        d32::finalize(value < 0, magnitude, 0, RoundingMode::TiesToEven).0
+    }
+}
+
+impl From<u32> for d32 {
+    fn from(value: u32) -> Self {
+        d32::finalize(false, value as u128, 0, RoundingMode::TiesToEven).0
+    }
+}
+
+impl core::fmt::Display for d32 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.write_string(f, false)
+    }
+}
+
+impl d32 {
+    #[cfg(feature = "alloc")]
+    pub fn to_parts(&self) -> DecimalParts {
+        let parts = self.to_fixed_parts();
+        DecimalParts {
+            sign: parts.sign,
+            coefficient_digits: parts.coefficient_digits().to_vec(),
+            exponent: parts.exponent,
+            special: parts.special,
+        }
+    }
+
+    pub fn to_fixed_parts(&self) -> FixedDecimalParts {
+        let mut parts = FixedDecimalParts {
+            sign: self.is_sign_minus(),
+            digits: [0; 7],
+            len: 0,
+            exponent: 0,
+            special: None,
+        };
+        if self.is_infinite() {
+            parts.special = Some(Special::Infinity);
+        } else if self.is_signaling() {
+            let payload = self.nan_payload();
+            parts.special = Some(Special::SignalingNaN { payload });
+        } else if self.is_nan() {
+            let payload = self.nan_payload();
+            parts.special = Some(Special::QuietNaN { payload });
+        } else {
+            let (_, mut coefficient, exponent) = self.decode();
+            parts.len = self.digits() as usize;
+            for d in parts.digits[..parts.len].iter_mut().rev() {
+                *d = (coefficient % 10) as u8;
+                coefficient /= 10;
+            }
+            parts.exponent = exponent;
+        }
+        parts
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn to_engineering_string(&self) -> String {
+        let mut s = String::new();
+        self.write_string(&mut s, true)
+            .expect("writing to a String can't fail");
+        s
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn to_hex_string(&self) -> String {
+        // the coefficient in hex and the exponent (still a
+        // power of ten) in signed decimal, as in -0x4d2p-2
+        // for -12.34; nothing is normalized, so cohorts and
+        // payloads come back intact through from_hex_string
+        let sign = if self.is_sign_minus() { "-" } else { "" };
+        if self.is_nan() {
+            let kind = if self.is_signaling() { "sNaN" } else { "NaN" };
+            format!("{}{}0x{:x}", sign, kind, self.nan_payload())
+        } else if self.is_infinite() {
+            format!("{}inf", sign)
+        } else {
+            let (_, c, e) = self.decode();
+            format!("{}0x{:x}p{:+}", sign, c, e)
+        }
+    }
+
+    pub fn from_hex_string(s: &str) -> Result<Self, ParseDecimalError> {
+        let (sign, rest) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+        if rest.is_empty() {
+            return Err(ParseDecimalError::Empty);
+        }
+        if rest.eq_ignore_ascii_case("inf") || rest.eq_ignore_ascii_case("infinity") {
+            return Ok(d32::infinity(sign));
+        }
+        let (signaling, payload) = match strip_prefix_ignore_case(rest, "snan") {
+            Some(payload) => (true, Some(payload)),
+            None => (false, strip_prefix_ignore_case(rest, "nan")),
+        };
+        if let Some(payload) = payload {
+            let payload = match strip_prefix_ignore_case(payload, "0x") {
+                Some(hex) => parse_hex(hex)?,
+                None if payload.is_empty() => 0,
+                None => return Err(ParseDecimalError::InvalidDigit),
+            };
+            if payload > 999999 {
+                return Err(ParseDecimalError::PayloadTooLarge);
+            }
+            return Ok(d32::nan_with_payload(sign, signaling, payload as u32));
+        }
+
+        let hex = strip_prefix_ignore_case(rest, "0x").ok_or(ParseDecimalError::InvalidDigit)?;
+        let (coefficient, exponent) = match hex.find(['p', 'P']) {
+            Some(i) => (parse_hex(&hex[..i])?, parse_exponent(&hex[i + 1..])?),
+            None => (parse_hex(hex)?, 0),
+        };
+        // anything that wasn't written by to_hex_string
+        // gets rounded like a decimal string would
+        let exponent = exponent.clamp(-1_000_000_000, 1_000_000_000) as i32;
+        Ok(d32::finalize(sign, coefficient, exponent, RoundingMode::TiesToEven).0)
+    }
+
+    fn write_string<W: core::fmt::Write>(&self, f: &mut W, engineering: bool) -> core::fmt::Result {
+        let parts = self.to_fixed_parts();
+        if parts.sign {
+            f.write_str("-")?;
+        }
+        let nan = |f: &mut W, kind: &str, payload: u32| match payload {
+            0 => f.write_str(kind),
+            payload => write!(f, "{}{}", kind, payload),
+        };
+        match parts.special {
+            Some(Special::Infinity) => return f.write_str("Infinity"),
+            Some(Special::QuietNaN { payload }) => return nan(f, "NaN", payload),
+            Some(Special::SignalingNaN { payload }) => return nan(f, "sNaN", payload),
+            None => {}
+        }
+
+        let mut buf = StackString::default();
+        for &d in parts.coefficient_digits() {
+            buf.write_char(char::from(b'0' + d))?;
+        }
+        let digits = buf.as_str();
+        let exponent = parts.exponent;
+        let n = digits.len() as i32;
+        // how many of the digits go in front of the point;
+        // plain notation puts it wherever the exponent says,
+        // scientific right after the first digit, and
+        // engineering wherever leaves a multiple of three
+        let leftdigits = exponent + n;
+        let dotplace = if exponent <= 0 && leftdigits > -6 {
+            leftdigits
+        } else if !engineering {
+            1
+        } else if digits == "0" {
+            // zeros get their exponent raised instead, taking
+            // on zeros after the point
+            (leftdigits + 1).rem_euclid(3) - 1
+        } else {
+            (leftdigits - 1).rem_euclid(3) + 1
+        };
+
+        let zeros = |f: &mut W, count: i32| (0..count).try_for_each(|_| f.write_char('0'));
+        if dotplace <= 0 {
+            f.write_str("0.")?;
+            zeros(f, -dotplace)?;
+            f.write_str(digits)?;
+        } else if dotplace >= n {
+            f.write_str(digits)?;
+            zeros(f, dotplace - n)?;
+        } else {
+            let (int, frac) = digits.split_at(dotplace as usize);
+            write!(f, "{}.{}", int, frac)?;
+        }
+        if leftdigits != dotplace {
+            write!(f, "E{:+}", leftdigits - dotplace)?;
+        }
+        Ok(())
+    }
+}
+
+impl core::fmt::Display for ParseDecimalError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let description = match self {
+            ParseDecimalError::Empty => "cannot parse decimal from empty string",
+            ParseDecimalError::InvalidDigit => "invalid digit found in string",
+            ParseDecimalError::InvalidExponent => "invalid exponent found in string",
+            ParseDecimalError::PayloadTooLarge => "nan payload too large to fit",
+            ParseDecimalError::InvalidGrouping => "misplaced digit group separator in string",
+        };
+        f.write_str(description)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseDecimalError {}
+
+impl core::fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let description = match self {
+            ConversionError::NotInteger => "decimal is not an integer",
+            ConversionError::OutOfRange => "decimal out of range of integral type",
+            ConversionError::Infinite => "cannot convert infinity to an integer",
+            ConversionError::NaN => "cannot convert nan to an integer",
+        };
+        f.write_str(description)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ConversionError {}
+
+impl core::fmt::Display for BuildError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let description = match self {
+            BuildError::CoefficientTooLarge => "coefficient has more than seven digits",
+            BuildError::ExponentOutOfRange => "exponent out of range of decimal32",
+        };
+        f.write_str(description)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BuildError {}
+
+impl d32 {
+    fn integer_value(&self) -> Result<i128, ConversionError> {
+        // exact integers only; anything bigger than an i128
+        // saturates, which is out of range all the same
+        if self.is_nan() {
+            return Err(ConversionError::NaN);
+        }
+        let (sign, magnitude, inexact) = self
+            .round_integral(RoundingMode::TowardZero)
+            .ok_or(ConversionError::Infinite)?;
+        if inexact {
+            return Err(ConversionError::NotInteger);
+        }
+        Ok(if sign {
+            -(magnitude as i128)
+        } else {
+            magnitude as i128
+        })
+    }
+}
+
+impl From<d32> for f64 {
+    fn from(x: d32) -> f64 {
+        x.to_f64()
+    }
+}
+
+impl From<d32> for f32 {
+    fn from(x: d32) -> f32 {
+        x.to_f32()
+    }
+}
+
+impl TryFrom<d32> for i32 {
+    type Error = ConversionError;
+
+    fn try_from(x: d32) -> Result<i32, ConversionError> {
+        i32::try_from(x.integer_value()?).map_err(|_| ConversionError::OutOfRange)
+    }
+}
+
+impl TryFrom<d32> for u32 {
+    type Error = ConversionError;
+
+    fn try_from(x: d32) -> Result<u32, ConversionError> {
+        u32::try_from(x.integer_value()?).map_err(|_| ConversionError::OutOfRange)
+    }
+}
+
+impl TryFrom<d32> for i64 {
+    type Error = ConversionError;
+
+    fn try_from(x: d32) -> Result<i64, ConversionError> {
+        i64::try_from(x.integer_value()?).map_err(|_| ConversionError::OutOfRange)
+    }
+}
+
+impl TryFrom<d32> for u64 {
+    type Error = ConversionError;
+
+    fn try_from(x: d32) -> Result<u64, ConversionError> {
+        u64::try_from(x.integer_value()?).map_err(|_| ConversionError::OutOfRange)
+    }
+}
+
+impl core::str::FromStr for d32 {
+    type Err = ParseDecimalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        d32::parse_with_options(s, ParseOptions::default())
+    }
+}
+
+impl d64 {
+    const PRECISION: u32 = 16;
+    const BIAS: i32 = 398;
+    const EMIN: i32 = -383;
+
+    pub const fn from_bits(bits: u64) -> Self {
+        d64(bits)
+    }
+
+    pub const fn to_bits(&self) -> u64 {
+        self.0
+    }
+
+    pub fn from_d32(x: d32) -> Self {
+        // every decimal32 fits, so this is exact; nans keep
+        // their payload and stay signaling if they were
+        if x.is_nan() {
+            let sign = if x.is_sign_minus() { 1 << 63 } else { 0 };
+            let nan = if x.is_signaling() {
+                0x7e00000000000000
+            } else {
+                0x7c00000000000000
+            };
+            return d64(sign | nan | d64::declets(x.nan_payload() as u64));
+        }
+        if x.is_infinite() {
+            return d64(((x.is_sign_minus() as u64) << 63) | 0x7800000000000000);
+        }
+        let (sign, c, e) = x.decode();
+        d64::encode(sign, c, e)
+    }
+
+    fn declets(mut value: u64) -> u64 {
+        // the low 15 digits, as five declets
+        let mut bits = 0;
+        for i in 0..5 {
+            bits |= (encode_declet((value % 1000) as u16) as u64) << (10 * i);
+            value /= 1000;
+        }
+        bits
+    }
+
+    fn trailing(&self) -> u64 {
+        let mut value = 0;
+        for i in (0..5).rev() {
+            value = value * 1000 + decode_declet(((self.0 >> (10 * i)) & 0x3ff) as u16) as u64;
+        }
+        value
+    }
+
+    fn exponent_form_one(&self) -> bool {
+        self.is_finite() && (self.0 & 0x6000000000000000 != 0x6000000000000000)
+    }
+
+    fn significand(&self) -> u64 {
+        // same layout as d32, just with more continuation
+        // bits and declets
+        let lead = if self.exponent_form_one() {
+            (self.0 >> 58) & 0x7
+        } else {
+            8 | ((self.0 >> 58) & 0x1)
+        };
+        lead * 10u64.pow(15) + self.trailing()
+    }
+
+    fn exponent(&self) -> u32 {
+        if !self.is_finite() {
+            0
+        } else if self.exponent_form_one() {
+            (((self.0 >> 53) & 0x300) | ((self.0 >> 50) & 0xff)) as u32
+        } else {
+            (((self.0 >> 51) & 0x300) | ((self.0 >> 50) & 0xff)) as u32
+        }
+    }
+
+    fn decode(&self) -> (bool, u64, i32) {
+        (
+            self.is_sign_minus(),
+            self.significand(),
+            self.exponent() as i32 - d64::BIAS,
+        )
+    }
+
+    fn encode(sign: bool, coefficient: u64, exponent: i32) -> Self {
+        // caller guarantees the coefficient has at most 16
+        // digits and the exponent is in range
+        let sign = if sign { 1 << 63 } else { 0 };
+        let biased = (exponent + d64::BIAS) as u64;
+        let lead = coefficient / 10u64.pow(15);
+        let combination = if lead < 8 {
+            (biased & 0x300) << 53 | lead << 58
+        } else {
+            0x6000000000000000 | (biased & 0x300) << 51 | (lead & 0x1) << 58
+        };
+        d64(sign | combination | (biased & 0xff) << 50 | d64::declets(coefficient))
+    }
+
+    fn nan_payload(&self) -> u64 {
+        self.trailing()
+    }
+
+    pub fn is_sign_minus(&self) -> bool {
+        self.0 >> 63 == 1
+    }
+
+    pub fn is_nan(&self) -> bool {
+        self.0 & 0x7c00000000000000 == 0x7c00000000000000
+    }
+
+    pub fn is_signaling(&self) -> bool {
+        self.0 & 0x7e00000000000000 == 0x7e00000000000000
+    }
+
+    pub fn is_infinite(&self) -> bool {
+        self.0 & 0x7c00000000000000 == 0x7800000000000000
+    }
+
+    pub fn is_finite(&self) -> bool {
+        !(self.is_infinite() || self.is_nan())
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.is_finite() && self.significand() == 0
+    }
+
+    pub fn is_subnormal(&self) -> bool {
+        if !self.is_finite() || self.is_zero() {
+            return false;
+        }
+        let (_, c, e) = self.decode();
+        e + digit_count(c as u128) as i32 - 1 < d64::EMIN
+    }
+
+    pub fn is_normal(&self) -> bool {
+        self.is_finite() && !self.is_zero() && !self.is_subnormal()
+    }
+
+    pub fn coefficient(&self) -> Option<u64> {
+        if self.is_finite() {
+            Some(self.significand())
+        } else {
+            None
+        }
+    }
+
+    pub fn unbiased_exponent(&self) -> Option<i32> {
+        if self.is_finite() {
+            Some(self.exponent() as i32 - d64::BIAS)
+        } else {
+            None
+        }
+    }
+
+    pub fn payload(&self) -> Option<u64> {
+        if self.is_nan() {
+            Some(self.nan_payload())
+        } else {
+            None
+        }
+    }
+
+    pub fn digits(&self) -> u32 {
+        match (self.coefficient(), self.payload()) {
+            (Some(c), _) | (_, Some(c)) => digit_count(c as u128).min(d64::PRECISION),
+            _ => 1,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for d32 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // the Display string, which keeps the cohort and
+        // any nan payload, and parses back to the same bits
+        // (noncanonical encodings aside)
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for d32 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = d32;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str("a decimal string")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, s: &str) -> Result<d32, E> {
+                s.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+// for use with #[serde(with = "dec754::serde_bits")], to store
+// the raw encoding as a u32 instead of a string
+#[cfg(feature = "serde")]
+pub mod serde_bits {
+    use super::d32;
+
+    pub fn serialize<S: serde::Serializer>(x: &d32, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u32(x.to_bits())
+    }
+
+    pub fn deserialize<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<d32, D::Error> {
+        <u32 as serde::Deserialize>::deserialize(deserializer).map(d32::from_bits)
+    }
+}
+
+// sound given repr(transparent) over a u32 and every bit
+// pattern being valid; zeroed is +0E-101
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for d32 {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for d32 {}
+
+#[cfg(feature = "rust_decimal")]
+impl From<rust_decimal::Decimal> for d32 {
+    fn from(value: rust_decimal::Decimal) -> Self {
+        // up to 29 digits at a scale of at most 28, so this can
+        // round but never overflow; the scale carries over as
+        // the exponent, trailing zeros and all
+        let magnitude = value.mantissa().unsigned_abs();
+        let exponent = -(value.scale() as i32);
+        let sign = value.is_sign_negative();
+        d32::finalize(sign, magnitude, exponent, RoundingMode::TiesToEven).0
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl TryFrom<d32> for rust_decimal::Decimal {
+    type Error = ConversionError;
+
+    fn try_from(x: d32) -> Result<Self, ConversionError> {
+        // exact or not at all: a decimal has the digits but
+        // not the range, topping out just under 8E28 and
+        // stopping at 28 places
+        if x.is_nan() {
+            return Err(ConversionError::NaN);
+        }
+        if x.is_infinite() {
+            return Err(ConversionError::Infinite);
+        }
+        let (sign, mut c, mut e) = x.decode();
+        let max_scale = rust_decimal::Decimal::MAX_SCALE as i32;
+        // trailing zeros can be shed to bring the scale down,
+        // and a zero can go all the way
+        while e < -max_scale && (c == 0 || c % 10 == 0) {
+            c /= 10;
+            e += 1;
+        }
+        if e < -max_scale {
+            return Err(ConversionError::OutOfRange);
+        }
+        let (mantissa, scale) = if e < 0 {
+            (c as i128, -e as u32)
+        } else {
+            let mantissa = 10i128
+                .checked_pow(e as u32)
+                .and_then(|p| p.checked_mul(c as i128))
+                .ok_or(ConversionError::OutOfRange)?;
+            (mantissa, 0)
+        };
+        let mut value = rust_decimal::Decimal::try_from_i128_with_scale(mantissa, scale)
+            .map_err(|_| ConversionError::OutOfRange)?;
+        value.set_sign_negative(sign);
+        Ok(value)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for d32 {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        // any bits at all, but canonical half the time so
+        // that noncanonical encodings don't crowd out the rest
+        let x = d32(u32::arbitrary(u)?);
+        Ok(if bool::arbitrary(u)? {
+            x.canonicalize()
+        } else {
+            x
+        })
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (5, Some(5))
+    }
+}
+
+// strategies for proptest: `any_bits` covers the whole encoding
+// space, and `edge_cases` leans on the values most likely to
+// trip something up
+#[cfg(feature = "proptest")]
+pub mod strategy {
+    use super::{d32, MAX_COEFFICIENT, QMAX, QMIN};
+    use proptest::prelude::*;
+
+    pub fn any_bits() -> impl Strategy<Value = d32> {
+        any::<u32>().prop_map(d32::from_bits)
+    }
+
+    pub fn finite() -> impl Strategy<Value = d32> {
+        (any::<bool>(), 0..=MAX_COEFFICIENT, QMIN..=QMAX)
+            .prop_map(|(sign, c, e)| d32::encode(sign, c, e))
+    }
+
+    pub fn edge_cases() -> impl Strategy<Value = d32> {
+        let boundaries = prop::sample::select(vec![
+            d32::ZERO,
+            d32::NEG_ZERO,
+            d32::ONE,
+            d32::MAX,
+            d32::MAX.negate(),
+            d32::MIN_POSITIVE,
+            d32::MIN_POSITIVE.negate(),
+            d32::MIN_POSITIVE.next_down(),
+            d32::encode(false, 1, QMIN),
+            d32::encode(true, 1, QMIN),
+            d32::encode(false, 0, QMIN),
+            d32::encode(true, 0, QMAX),
+            d32::INFINITY,
+            d32::NEG_INFINITY,
+            d32::NAN,
+            d32::NAN.negate(),
+            d32::signaling_nan(false, 0),
+            d32::signaling_nan(true, 999999),
+        ]);
+        // the extremes of the exponent range, with any
+        // coefficient and either sign
+        let extremes = (
+            any::<bool>(),
+            0..=MAX_COEFFICIENT,
+            prop::sample::select(vec![QMIN, QMAX]),
+        )
+            .prop_map(|(sign, c, e)| d32::encode(sign, c, e));
+        prop_oneof![
+            3 => boundaries,
+            2 => extremes,
+            2 => finite(),
+            1 => any_bits(),
+        ]
+    }
+}
+
+// c bindings: a d32 is passed as its uint32_t encoding, rounding
+// modes are numbered in RoundingMode's order (anything else is
+// ties to even), and as with the operators, the flags are
+// dropped
+#[cfg(feature = "ffi")]
+pub mod ffi {
+    use super::{d32, Comparison, RoundingMode};
+    use core::ffi::c_int;
+
+    fn rounding(mode: c_int) -> RoundingMode {
+        match mode {
+            1 => RoundingMode::TiesToAway,
+            2 => RoundingMode::TowardZero,
+            3 => RoundingMode::TowardPositive,
+            4 => RoundingMode::TowardNegative,
+            _ => RoundingMode::TiesToEven,
+        }
+    }
+
+    #[no_mangle]
+    pub extern "C" fn d32_from_bits(bits: u32) -> d32 {
+        d32::from_bits(bits)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn d32_to_bits(x: d32) -> u32 {
+        x.to_bits()
+    }
+
+    #[no_mangle]
+    pub extern "C" fn d32_from_i32(value: i32) -> d32 {
+        d32::from(value)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn d32_from_f64(value: f64, mode: c_int) -> d32 {
+        d32::from_f64(value, rounding(mode))
+    }
+
+    #[no_mangle]
+    pub extern "C" fn d32_to_f64(x: d32) -> f64 {
+        x.to_f64()
+    }
+
+    #[no_mangle]
+    pub extern "C" fn d32_add(x: d32, y: d32, mode: c_int) -> d32 {
+        x.add(&y, rounding(mode))
+    }
+
+    #[no_mangle]
+    pub extern "C" fn d32_sub(x: d32, y: d32, mode: c_int) -> d32 {
+        x.subtract(&y, rounding(mode))
+    }
+
+    #[no_mangle]
+    pub extern "C" fn d32_mul(x: d32, y: d32, mode: c_int) -> d32 {
+        x.multiply(&y, rounding(mode))
+    }
+
+    #[no_mangle]
+    pub extern "C" fn d32_div(x: d32, y: d32, mode: c_int) -> d32 {
+        x.divide(&y, rounding(mode))
+    }
+
+    #[no_mangle]
+    pub extern "C" fn d32_fma(x: d32, y: d32, z: d32, mode: c_int) -> d32 {
+        x.fused_multiply_add(&y, &z, rounding(mode))
+    }
+
+    #[no_mangle]
+    pub extern "C" fn d32_sqrt(x: d32, mode: c_int) -> d32 {
+        x.sqrt(rounding(mode))
+    }
+
+    #[no_mangle]
+    pub extern "C" fn d32_quantize(x: d32, y: d32, mode: c_int) -> d32 {
+        x.quantize(&y, rounding(mode))
+    }
+
+    // -1, 0 or 1 as x is less, equal or greater, and 2 when
+    // either is a nan
+    #[no_mangle]
+    pub extern "C" fn d32_compare(x: d32, y: d32) -> c_int {
+        match x.compare(&y) {
+            Comparison::Less => -1,
+            Comparison::Equal => 0,
+            Comparison::Greater => 1,
+            Comparison::Unordered => 2,
+        }
+    }
+
+    #[no_mangle]
+    pub extern "C" fn d32_total_order(x: d32, y: d32) -> bool {
+        x.total_order(&y)
+    }
+
+    // class_number's numbering, from 0 for signaling nans to
+    // 9 for positive infinity
+    #[no_mangle]
+    pub extern "C" fn d32_class(x: d32) -> c_int {
+        x.class_number()
+    }
+}
+
+// a d32 literal, e.g. dec32!(-3.14E-2), with the exact cohort
+// written (so dec32!(1.250) has exponent -3), worked out at
+// compile time; too many digits or an exponent out of range
+// won't compile
+#[macro_export]
+macro_rules! dec32 {
+    ($($literal:tt)+) => {{
+        const VALUE: $crate::d32 = $crate::d32::from_literal(stringify!($($literal)+));
+        VALUE
+    }};
+}
+
+fn parse_payload(s: &str) -> Result<u32, ParseDecimalError> {
+    // up to 6 digits fit in the trailing significand
+    if !s.bytes().all(|c| c.is_ascii_digit()) {
+        return Err(ParseDecimalError::InvalidDigit);
+    }
+    let digits = s.trim_start_matches('0');
+    if digits.len() > 6 {
+        return Err(ParseDecimalError::PayloadTooLarge);
+    }
+    Ok(digits.parse().unwrap_or(0))
+}
+
+fn parse_exponent(s: &str) -> Result<i64, ParseDecimalError> {
+    let (negative, digits) = match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+    if digits.is_empty() || !digits.bytes().all(|c| c.is_ascii_digit()) {
+        return Err(ParseDecimalError::InvalidExponent);
+    }
+    // saturate rather than fail on absurdly long exponents
+    let magnitude = digits.bytes().fold(0i64, |acc, c| {
+        (acc * 10 + (c - b'0') as i64).min(1_000_000_000_000)
+    });
+    Ok(if negative { -magnitude } else { magnitude })
+}
+
+fn strip_prefix_ignore_case<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+    // all our prefixes are ascii, so any match ends on a
+    // char boundary
+    match s.as_bytes().get(..prefix.len()) {
+        Some(start) if start.eq_ignore_ascii_case(prefix.as_bytes()) => Some(&s[prefix.len()..]),
+        _ => None,
+    }
+}
+
+fn parse_hex(s: &str) -> Result<u128, ParseDecimalError> {
+    // 32 hex digits is all a u128 holds, but that's already
+    // far more than any coefficient needs
+    let digits = s.trim_start_matches('0');
+    if s.is_empty() || digits.len() > 32 || !s.bytes().all(|c| c.is_ascii_hexdigit()) {
+        return Err(ParseDecimalError::InvalidDigit);
+    }
+    Ok(u128::from_str_radix(digits, 16).unwrap_or(0))
+}
+
+fn class_of_bits(bits: u32) -> Class {
+    // class, worked out from the bits as far as it can be:
+    // a zero is a first-form encoding with no lead digit and
+    // zero declets (noncanonical declets are never zero), and
+    // only biased exponents under 6 can be subnormal, so
+    // those alone need the digits counted
+    let minus = bits & 0x80000000 != 0;
+    match (bits >> 26) & 0x1f {
+        0x1f if bits & 0x02000000 != 0 => Class::SignalingNaN,
+        0x1f => Class::QuietNaN,
+        0x1e if minus => Class::NegativeInf,
+        0x1e => Class::PositiveInf,
+        _ => {
+            let x = d32(bits);
+            let zero = bits & 0x60000000 != 0x60000000 && bits & 0x1c0fffff == 0;
+            match (minus, zero, !zero && x.exponent() < 6 && x.is_subnormal()) {
+                (true, true, _) => Class::NegativeZero,
+                (true, _, true) => Class::NegativeSubnormal,
+                (true, _, _) => Class::NegativeNormal,
+                (false, true, _) => Class::PositiveZero,
+                (false, _, true) => Class::PositiveSubnormal,
+                (false, _, _) => Class::PositiveNormal,
+            }
+        }
+    }
+}
+
+fn decode_declet(declet: u16) -> u16 {
+    // densely packed decimal: b3 set means at least one of
+    // the three digits is 8 or 9, and then b2 b1 (and b6 b5
+    // when both are set) say which; big digits only keep
+    // their low bit, and the small ones' bits move around
+    let b = |i: u16| (declet >> i) & 1;
+    let (d2, d1, d0) = if b(3) == 0 {
+        (declet >> 7 & 0x7, declet >> 4 & 0x7, declet & 0x7)
+    } else {
+        match (b(2), b(1), b(6), b(5)) {
+            (0, 0, _, _) => (declet >> 7 & 0x7, declet >> 4 & 0x7, 8 | b(0)),
+            (0, 1, _, _) => (declet >> 7 & 0x7, 8 | b(4), (declet >> 4 & 0x6) | b(0)),
+            (1, 0, _, _) => (8 | b(7), declet >> 4 & 0x7, (declet >> 7 & 0x6) | b(0)),
+            (_, _, 0, 0) => (8 | b(7), 8 | b(4), (declet >> 7 & 0x6) | b(0)),
+            (_, _, 0, 1) => (8 | b(7), (declet >> 7 & 0x6) | b(4), 8 | b(0)),
+            (_, _, 1, 0) => (declet >> 7 & 0x7, 8 | b(4), 8 | b(0)),
+            _ => (8 | b(7), 8 | b(4), 8 | b(0)),
+        }
+    };
+    d2 * 100 + d1 * 10 + d0
+}
+
+const fn encode_declet(value: u16) -> u16 {
+    // the inverse of decode_declet, for values up to 999
+    let (d2, d1, d0) = (value / 100, value / 10 % 10, value % 10);
+    match (d2 > 7, d1 > 7, d0 > 7) {
+        (false, false, false) => d2 << 7 | d1 << 4 | d0,
+        (false, false, true) => d2 << 7 | d1 << 4 | 0x8 | (d0 & 1),
+        (false, true, false) => d2 << 7 | (d0 & 0x6) << 4 | (d1 & 1) << 4 | 0xa | (d0 & 1),
+        (true, false, false) => (d0 & 0x6) << 7 | (d2 & 1) << 7 | d1 << 4 | 0xc | (d0 & 1),
+        (true, true, false) => (d0 & 0x6) << 7 | (d2 & 1) << 7 | (d1 & 1) << 4 | 0xe | (d0 & 1),
+        (true, false, true) => {
+            (d1 & 0x6) << 7 | (d2 & 1) << 7 | 0x20 | (d1 & 1) << 4 | 0xe | (d0 & 1)
+        }
+        (false, true, true) => d2 << 7 | 0x40 | (d1 & 1) << 4 | 0xe | (d0 & 1),
+        (true, true, true) => (d2 & 1) << 7 | 0x60 | (d1 & 1) << 4 | 0xe | (d0 & 1),
+    }
+}
+
+fn exact_digits(mantissa: u64, factor: u64, power: u32) -> impl Iterator<Item = u8> + Clone {
+    // big-endian decimal digits of mantissa * factor^power,
+    // worked out in little-endian limbs of nine digits; the
+    // biggest we need is a 53-bit mantissa times 5^1074,
+    // about 770 digits, so 96 limbs is plenty
+    const LIMB: u64 = 1_000_000_000;
+    let mut limbs = [0u64; 96];
+    limbs[0] = mantissa % LIMB;
+    limbs[1] = mantissa / LIMB % LIMB;
+    limbs[2] = mantissa / LIMB / LIMB;
+    let mut len = 3;
+    for _ in 0..power {
+        let mut carry = 0;
+        for limb in limbs[..len].iter_mut() {
+            let x = *limb * factor + carry;
+            *limb = x % LIMB;
+            carry = x / LIMB;
+        }
+        if carry != 0 {
+            limbs[len] = carry;
+            len += 1;
+        }
+    }
+    (0..len).rev().flat_map(move |i| {
+        (0..9)
+            .rev()
+            .map(move |place| (limbs[i] / 10u64.pow(place) % 10) as u8)
+    })
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct OrderKey {
+    // field order is comparison order
+    rank: u8,
+    adjusted: i32,
+    padded: u32,
+    exponent: i32,
+}
+
+#[derive(Default)]
+struct StackString {
+    // just enough of a String for formatting a coefficient
+    // and exponent, for when there's no allocator
+    bytes: [u8; 32],
+    len: usize,
+}
+
+impl StackString {
+    fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.bytes[..self.len]).expect("only ever written a &str")
+    }
+}
+
+impl core::fmt::Write for StackString {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let end = self.len + s.len();
+        if end > self.bytes.len() {
+            return Err(core::fmt::Error);
+        }
+        self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+fn digit_count(mut x: u128) -> u32 {
+    let mut digits = 1;
+    while x >= 10 {
+        x /= 10;
+        digits += 1;
+    }
+    digits
+}
+
+fn terminating_reciprocal(c: u128, n: u32) -> Option<(u128, u32)> {
+    // 1 / c^n as q / 10^places, for a c with no factors but
+    // twos or fives (and no tens), if q fits; 1 / 2^k is
+    // 5^k / 10^k and the other way round for fives
+    let (factor, k) = if c.is_power_of_two() {
+        (5u128, c.trailing_zeros())
+    } else {
+        let (mut rest, mut k) = (c, 0);
+        while rest.is_multiple_of(5) {
+            rest /= 5;
+            k += 1;
+        }
+        if rest != 1 {
+            return None;
+        }
+        (2u128, k)
+    };
+    let places = k.checked_mul(n)?;
+    Some((factor.checked_pow(places)?, places))
+}
+
+fn pow10(n: u32) -> u128 {
+    10u128.pow(n)
+}
+
+fn isqrt(n: u128) -> u128 {
+    // newton's method, starting from above
+    if n < 2 {
+        return n;
+    }
+    let mut x = n;
+    let mut y = x.div_ceil(2);
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
+}
+
+fn iroot(n: u128, k: u32) -> u128 {
+    // the largest r with r^k <= n, by bisection
+    let bound = 1u128.checked_shl(128 / k + 1).unwrap_or(u128::MAX);
+    let (mut low, mut high) = (0u128, n.min(bound));
+    while low < high {
+        let mid = low + (high - low).div_ceil(2);
+        if mid.checked_pow(k).is_some_and(|p| p <= n) {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+    low
+}
+
+fn round_off(coefficient: u128, drop: u32, sign: bool, mode: RoundingMode) -> (u64, bool) {
+    // rounds off the last `drop` digits of the coefficient;
+    // spell out the digits behind a leading zero, so that
+    // dropping every last one still leaves a zero to round
+    // on, and dropping any further changes nothing
+    let digits = digit_count(coefficient);
+    let mut buf = [0u8; 40];
+    let mut rest = coefficient;
+    for i in (1..=digits as usize).rev() {
+        buf[i] = (rest % 10) as u8;
+        rest /= 10;
+    }
+    let keep = (digits as i32 - drop.min(digits + 1) as i32) + 1;
+    round_coefficient(&buf[..=digits as usize], keep as usize, sign, mode)
+}
+
+fn round_coefficient(digits: &[u8], keep: usize, sign: bool, mode: RoundingMode) -> (u64, bool) {
+    // rounds a big-endian string of decimal digits to its
+    // first `keep`, giving back the new coefficient (which
+    // can carry into an extra digit) and whether anything
+    // nonzero got thrown away
+    use core::cmp::Ordering;
+    let (kept, dropped) = digits.split_at(keep.min(digits.len()));
+    let mut coefficient = kept.iter().fold(0, |acc, &d| acc * 10 + d as u64);
+    let inexact = dropped.iter().any(|&d| d != 0);
+    if inexact {
+        let rest_nonzero = dropped[1..].iter().any(|&d| d != 0);
+        let half = dropped[0].cmp(&5).then(if rest_nonzero {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        });
+        if round_away(sign, coefficient & 1 == 1, half, mode) {
+            coefficient += 1;
+        }
+    }
+    (coefficient, inexact)
+}
+
+fn round_away(sign: bool, odd: bool, half: core::cmp::Ordering, mode: RoundingMode) -> bool {
+    // decides whether an inexact coefficient gets bumped up
+    // in magnitude; `half` is how the discarded digits
+    // compare to exactly one half
+    use core::cmp::Ordering;
+    match mode {
+        RoundingMode::TiesToEven => half == Ordering::Greater || (half == Ordering::Equal && odd),
+        RoundingMode::TiesToAway => half != Ordering::Less,
+        RoundingMode::TowardZero => false,
+        RoundingMode::TowardPositive => !sign,
+        RoundingMode::TowardNegative => sign,
+    }
+}
+
+// fixed point with 32 decimal places, for the transcendental
+// functions: enough that 7 digits come out right barring an
+// exact result within about 1e-25 of a rounding boundary
+const FIXED_ONE: i128 = 100_000_000_000_000_000_000_000_000_000_000;
+const FIXED_LN10: i128 = 230_258_509_299_404_568_401_799_145_468_436;
+const FIXED_SQRT10: i128 = 316_227_766_016_837_933_199_889_354_443_272;
+// a generous bound on the error of fixed_ln and fixed_exp,
+// in units of the last place
+const FIXED_ERROR: u128 = 100_000;
+
+fn fixed_mul(a: i128, b: i128) -> i128 {
+    // a * b, truncated; splitting each operand in half keeps
+    // the partial products in range so long as the result is
+    // under a million or so
+    const HALF: u128 = 10_000_000_000_000_000;
+    let (x, y) = (a.unsigned_abs(), b.unsigned_abs());
+    let (x1, x0, y1, y0) = (x / HALF, x % HALF, y / HALF, y % HALF);
+    let product = x1 * y1 + (x1 * y0 + x0 * y1 + x0 * y0 / HALF) / HALF;
+    if (a < 0) != (b < 0) {
+        -(product as i128)
+    } else {
+        product as i128
+    }
+}
+
+fn fixed_div(a: i128, b: i128) -> i128 {
+    // a / b, truncated, by long division four digits at a
+    // time; b has to stay under a hundred
+    let (x, y) = (a.unsigned_abs(), b.unsigned_abs());
+    let mut quotient = x / y;
+    let mut rest = x % y;
+    for _ in 0..8 {
+        rest *= 10_000;
+        quotient = quotient * 10_000 + rest / y;
+        rest %= y;
+    }
+    if (a < 0) != (b < 0) {
+        -(quotient as i128)
+    } else {
+        quotient as i128
+    }
+}
+
+fn fixed_ln(coefficient: u128, exponent: i32) -> i128 {
+    // ln(m * 10^k) = ln(m) + k ln(10), with m between 1/sqrt(10)
+    // and sqrt(10), then ln(m) = 2 atanh((m - 1) / (m + 1)),
+    // whose series has only odd powers of something under 0.52;
+    // past 33 digits the coefficient is cut short
+    let digits = digit_count(coefficient);
+    let mut k = exponent + digits as i32 - 1;
+    let mut m = if digits > 33 {
+        coefficient / pow10(digits - 33)
+    } else {
+        coefficient * pow10(33 - digits)
+    } as i128;
+    if m >= FIXED_SQRT10 {
+        m /= 10;
+        k += 1;
+    }
+    let z = fixed_div(m - FIXED_ONE, m + FIXED_ONE);
+    let z2 = fixed_mul(z, z);
+    let mut term = z;
+    let mut sum = 0;
+    let mut n = 1;
+    while term != 0 {
+        sum += term / n;
+        term = fixed_mul(term, z2);
+        n += 2;
+    }
+    2 * sum + k as i128 * FIXED_LN10
+}
+
+fn fixed_mul_decimal(a: u128, coefficient: u64, exponent: i32) -> u128 {
+    // a * coefficient * 10^exponent, truncated; the caller
+    // makes sure the product is in range, and a is at most a
+    // few hundred so each half times the coefficient fits
+    const HALF: u128 = 10_000_000_000_000_000;
+    let (high, low) = (
+        a / HALF * coefficient as u128,
+        a % HALF * coefficient as u128,
+    );
+    if exponent >= 0 {
+        return (high * HALF + low) * pow10(exponent as u32);
+    }
+    let shift = exponent.unsigned_abs();
+    let high = if shift <= 16 {
+        high * pow10(16 - shift)
+    } else {
+        high / pow10((shift - 16).min(38))
+    };
+    high + low / pow10(shift.min(38))
+}
+
+fn fixed_exp(x: i128) -> (u128, i32) {
+    // exp(x) = exp(r) 10^k with x = r + k ln(10) and r under
+    // ln(10), so exp(r) comes out of its taylor series between
+    // one and ten; gives back the fixed point exp(r) and k
+    let k = x.div_euclid(FIXED_LN10);
+    let r = x - k * FIXED_LN10;
+    let mut term = FIXED_ONE;
+    let mut sum = FIXED_ONE;
+    let mut n = 1;
+    while term != 0 {
+        term = fixed_mul(term, r) / n;
+        sum += term;
+        n += 1;
+    }
+    (sum as u128, k as i32)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    fn dec(sign: bool, coefficient: u64, exponent: i32) -> d32 {
+        d32::encode(sign, coefficient, exponent)
+    }
+
+    fn parts(x: d32) -> (bool, u64, i32) {
+        x.decode()
+    }
+
+    #[test]
+    fn decode_declet_vectors() {
+        let vectors = [
+            (0x000, 0),
+            (0x005, 5),
+            (0x009, 9),
+            (0x055, 55),
+            (0x079, 79),
+            (0x00a, 80),
+            (0x05f, 99),
+            (0x07e, 898),
+            (0x06e, 888),
+            (0x2d5, 555),
+            (0x2dd, 955),
+            (0x09a, 190),
+            (0x0ac, 920),
+            (0x0ff, 999),
+            (0x1ff, 999),
+            (0x2ff, 999),
+            (0x3ff, 999),
+            (0x16e, 888),
+            (0x26e, 888),
+            (0x36e, 888),
+        ];
+        for &(declet, value) in vectors.iter() {
+            assert_eq!(decode_declet(declet), value, "{:#x}", declet);
+        }
+    }
+
+    #[test]
+    fn declets_round_trip() {
+        for value in 0..1000 {
+            assert_eq!(decode_declet(encode_declet(value)), value);
+        }
+        for declet in 0..1024 {
+            assert!(decode_declet(declet) < 1000);
+        }
+    }
+
+    #[test]
+    fn encode_declet_vectors() {
+        let vectors = [
+            (0, 0x000),
+            (9, 0x009),
+            (55, 0x055),
+            (79, 0x079),
+            (80, 0x00a),
+            (99, 0x05f),
+            (190, 0x09a),
+            (555, 0x2d5),
+            (888, 0x06e),
+            (898, 0x07e),
+            (920, 0x0ac),
+            (955, 0x2dd),
+            (999, 0x0ff),
+        ];
+        for &(value, declet) in vectors.iter() {
+            assert_eq!(encode_declet(value), declet, "{}", value);
+        }
+    }
+
+    #[test]
+    fn from_parts_exact() {
+        let x = d32::from_parts(false, 1234567, 0);
+        assert_eq!(x.0, 0x26500000 | 0x134 << 10 | 0x2e7);
+        let x = d32::from_parts(false, 9876543, QMIN);
+        assert_eq!(x.0, 0x64000000 | 0x37c << 10 | 0x2c3);
+        assert!(x.is_canonical());
+        assert_eq!(parts(d32::from_parts(true, 0, 7)), (true, 0, 7));
+    }
+
+    #[test]
+    fn try_from_parts_is_strict() {
+        let x = d32::try_from_parts(true, 9999999, QMAX).unwrap();
+        assert_eq!(parts(x), (true, 9999999, QMAX));
+        assert_eq!(x, d32::MAX.negate());
+        let x = d32::try_from_parts(false, 1, QMIN).unwrap();
+        assert_eq!(x, d32::MIN_POSITIVE_SUBNORMAL);
+        assert_eq!(
+            parts(d32::try_from_parts(false, 1200, -2).unwrap()),
+            (false, 1200, -2)
+        );
+        assert_eq!(
+            parts(d32::try_from_parts(true, 0, 7).unwrap()),
+            (true, 0, 7)
+        );
+        // where from_parts would round, saturate or pad
+        assert_eq!(
+            d32::try_from_parts(false, 10000000, 0),
+            Err(BuildError::CoefficientTooLarge)
+        );
+        assert_eq!(
+            d32::try_from_parts(false, u32::MAX, QMIN - 50),
+            Err(BuildError::CoefficientTooLarge)
+        );
+        assert_eq!(
+            d32::try_from_parts(false, 12, QMAX + 1),
+            Err(BuildError::ExponentOutOfRange)
+        );
+        assert_eq!(
+            d32::try_from_parts(true, 5, QMIN - 1),
+            Err(BuildError::ExponentOutOfRange)
+        );
+        assert_eq!(
+            d32::try_from_parts(false, 0, i32::MIN),
+            Err(BuildError::ExponentOutOfRange)
+        );
+        assert_eq!(
+            BuildError::CoefficientTooLarge.to_string(),
+            "coefficient has more than seven digits"
+        );
+    }
+
+    #[test]
+    fn from_parts_normalizes() {
+        // too many digits round to nearest
+        assert_eq!(
+            parts(d32::from_parts(false, 123456789, 0)),
+            (false, 1234568, 2)
+        );
+        // high exponents pad with zeros, then overflow
+        assert_eq!(
+            parts(d32::from_parts(false, 12, QMAX + 3)),
+            (false, 12000, QMAX)
+        );
+        assert!(d32::from_parts(true, 12, QMAX + 6).is_infinite());
+        assert!(d32::from_parts(true, 12, QMAX + 6).is_sign_minus());
+        // low exponents drop digits, then underflow to zero
+        assert_eq!(
+            parts(d32::from_parts(false, 1500, QMIN - 3)),
+            (false, 2, QMIN)
+        );
+        assert_eq!(
+            parts(d32::from_parts(false, 15, i32::MIN / 2)),
+            (false, 0, QMIN)
+        );
+        assert_eq!(
+            parts(d32::from_parts(false, 0, i32::MAX / 2)),
+            (false, 0, QMAX)
+        );
+    }
+
+    #[test]
+    fn significand_reads_declets() {
+        // 1234567E0: lead 1 in the first form, declets 234, 567
+        let x = d32(0x26500000 | 0x134 << 10 | 0x2e7);
+        assert_eq!(x.significand(), 1234567);
+        assert_eq!(x.exponent(), 101);
+        // 9876543E-101: lead 9 in the second form
+        let x = d32(0x64000000 | 0x37c << 10 | 0x2c3);
+        assert_eq!(x.significand(), 9876543);
+        assert_eq!(x.exponent(), 0);
+        for &(sign, coefficient, exponent) in [
+            (false, 1234567, 0),
+            (true, 9876543, QMIN),
+            (false, 8000000, QMAX),
+            (true, 42, -3),
+        ]
+        .iter()
+        {
+            assert_eq!(
+                parts(dec(sign, coefficient, exponent)),
+                (sign, coefficient, exponent)
+            );
+        }
+    }
+
+    #[test]
+    fn binary_encoding_known_values() {
+        let one = dec(false, 1, 0);
+        assert_eq!(one.0, 0x22500001);
+        assert_eq!(one.encode_binary().0, 0x32800001);
+        assert_eq!(d32(0x32800001).decode_binary().0, 0x22500001);
+        // 9999999 needs the second bid form
+        let x = dec(true, 9999999, 0);
+        assert_eq!(x.encode_binary().0, 0xecb8967f);
+        assert_eq!(d32(0xecb8967f).decode_binary().0, x.0);
+        // out-of-range bid coefficients read as zero
+        assert_eq!(parts(d32(0x6cbfffff).decode_binary()), (false, 0, 0));
+    }
+
+    #[test]
+    fn binary_encoding_round_trips() {
+        let coefficients = [
+            0, 1, 7, 42, 999, 1000, 123456, 999999, 7999999, 8000000, 8388607, 8388608, 9999999,
+        ];
+        let exponents = [QMIN, -50, -1, 0, 1, 37, QMAX];
+        for &c in coefficients.iter() {
+            for &e in exponents.iter() {
+                for &sign in [false, true].iter() {
+                    let x = dec(sign, c, e);
+                    let bid = x.encode_binary();
+                    assert_eq!(bid.0 & 0x80000000 != 0, sign);
+                    assert_eq!(bid.decode_binary().0, x.0);
+                }
+            }
+        }
+        let nan = parse("-sNaN123456");
+        assert_eq!(nan.encode_binary().0, 0xfe000000 | 123456);
+        assert_eq!(nan.encode_binary().decode_binary().0, nan.0);
+        let inf = d32::infinity(true);
+        assert_eq!(inf.encode_binary().decode_binary().0, inf.0);
+    }
+
+    #[test]
+    fn round_coefficient_ties() {
+        // 12.5 and 13.5, both signs
+        let even = [1, 2, 5];
+        let odd = [1, 3, 5];
+        let cases = [
+            (RoundingMode::TiesToEven, 12, 14, 12, 14),
+            (RoundingMode::TiesToAway, 13, 14, 13, 14),
+            (RoundingMode::TowardZero, 12, 13, 12, 13),
+            (RoundingMode::TowardPositive, 13, 14, 12, 13),
+            (RoundingMode::TowardNegative, 12, 13, 13, 14),
+        ];
+        for &(mode, pos_even, pos_odd, neg_even, neg_odd) in cases.iter() {
+            assert_eq!(round_coefficient(&even, 2, false, mode), (pos_even, true));
+            assert_eq!(round_coefficient(&odd, 2, false, mode), (pos_odd, true));
+            assert_eq!(round_coefficient(&even, 2, true, mode), (neg_even, true));
+            assert_eq!(round_coefficient(&odd, 2, true, mode), (neg_odd, true));
+        }
+    }
+
+    #[test]
+    fn round_coefficient_near_half() {
+        // just above and just below the halfway point
+        let above = [1, 2, 5, 0, 1];
+        let below = [1, 2, 4, 9, 9];
+        assert_eq!(
+            round_coefficient(&above, 2, false, RoundingMode::TiesToEven),
+            (13, true)
+        );
+        assert_eq!(
+            round_coefficient(&below, 2, false, RoundingMode::TiesToAway),
+            (12, true)
+        );
+        assert_eq!(
+            round_coefficient(&below, 2, false, RoundingMode::TowardPositive),
+            (13, true)
+        );
+        // exact, and a carry into a new digit
+        assert_eq!(
+            round_coefficient(&[1, 2, 0, 0], 2, true, RoundingMode::TowardNegative),
+            (12, false)
+        );
+        assert_eq!(
+            round_coefficient(&[9, 9, 7], 2, false, RoundingMode::TiesToEven),
+            (100, true)
+        );
+    }
+
+    #[test]
+    fn add_exact() {
+        // 1.23 + 4.5 = 5.73
+        let x = dec(false, 123, -2).add(&dec(false, 45, -1), RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (false, 573, -2));
+        // 1.00 + -3 = -2.00
+        let x = dec(false, 100, -2).add(&dec(true, 3, 0), RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (true, 200, -2));
+        // 9999999 + 1 needs an eighth digit, which is a zero
+        let x = dec(false, 9999999, 0).add(&dec(false, 1, 0), RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (false, 1000000, 1));
+    }
+
+    #[test]
+    fn add_rounds() {
+        let a = dec(false, 1234567, 0);
+        let half = dec(false, 5, -1);
+        assert_eq!(
+            parts(a.add(&half, RoundingMode::TiesToEven)),
+            (false, 1234568, 0)
+        );
+        assert_eq!(
+            parts(a.add(&half, RoundingMode::TowardZero)),
+            (false, 1234567, 0)
+        );
+        let a = dec(false, 1234568, 0);
+        assert_eq!(
+            parts(a.add(&half, RoundingMode::TiesToEven)),
+            (false, 1234568, 0)
+        );
+        assert_eq!(
+            parts(a.add(&half, RoundingMode::TiesToAway)),
+            (false, 1234569, 0)
+        );
+        // a tiny operand far below only nudges directed rounding
+        let tiny = dec(true, 1, QMIN);
+        let big = dec(false, 1000000, 10);
+        assert_eq!(
+            parts(big.add(&tiny, RoundingMode::TiesToEven)),
+            (false, 1000000, 10)
+        );
+        assert_eq!(
+            parts(big.add(&tiny, RoundingMode::TowardZero)),
+            (false, 9999999, 9)
+        );
+    }
+
+    #[test]
+    fn add_specials() {
+        let inf = d32::infinity(false);
+        let ninf = d32::infinity(true);
+        let one = dec(false, 1, 0);
+        assert!(inf.add(&ninf, RoundingMode::TiesToEven).is_nan());
+        assert!(ninf.add(&one, RoundingMode::TiesToEven).is_infinite());
+        assert!(ninf.add(&one, RoundingMode::TiesToEven).is_sign_minus());
+        let snan = d32(0x7e000123);
+        let x = snan.add(&one, RoundingMode::TiesToEven);
+        assert!(x.is_nan() && !x.is_signaling());
+        assert_eq!(x.0 & 0x000fffff, 0x123);
+    }
+
+    #[test]
+    fn add_zero_sign() {
+        let x = dec(false, 15, -1);
+        let y = dec(true, 15, -1);
+        let sum = x.add(&y, RoundingMode::TiesToEven);
+        assert!(sum.is_zero() && !sum.is_sign_minus());
+        let sum = x.add(&y, RoundingMode::TowardNegative);
+        assert!(sum.is_zero() && sum.is_sign_minus());
+        let sum = dec(true, 0, 0).add(&dec(true, 0, -3), RoundingMode::TiesToEven);
+        assert_eq!(parts(sum), (true, 0, -3));
+    }
+
+    #[test]
+    fn add_overflow() {
+        let max = dec(false, 9999999, QMAX);
+        let one = dec(false, 1, QMAX);
+        assert!(max.add(&one, RoundingMode::TiesToEven).is_infinite());
+        assert_eq!(
+            parts(max.add(&one, RoundingMode::TowardZero)),
+            (false, 9999999, QMAX)
+        );
+    }
+
+    #[test]
+    fn subtract_matches_add() {
+        let values = [
+            dec(false, 123, -2),
+            dec(true, 45, -1),
+            dec(false, 9999999, 3),
+            dec(true, 1, QMIN),
+            dec(false, 0, 5),
+            dec(true, 0, -7),
+        ];
+        let modes = [
+            RoundingMode::TiesToEven,
+            RoundingMode::TiesToAway,
+            RoundingMode::TowardZero,
+            RoundingMode::TowardPositive,
+            RoundingMode::TowardNegative,
+        ];
+        for a in values.iter() {
+            for b in values.iter() {
+                for &mode in modes.iter() {
+                    assert_eq!(a.subtract(b, mode).0, a.add(&b.negate(), mode).0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn subtract_zero_sign() {
+        let x = dec(true, 2500, -3);
+        let diff = x.subtract(&x, RoundingMode::TowardNegative);
+        assert_eq!(parts(diff), (true, 0, -3));
+        for &mode in [
+            RoundingMode::TiesToEven,
+            RoundingMode::TowardZero,
+            RoundingMode::TowardPositive,
+        ]
+        .iter()
+        {
+            assert_eq!(parts(x.subtract(&x, mode)), (false, 0, -3));
+        }
+    }
+
+    #[test]
+    fn subtract_nan() {
+        let snan = d32(0x7e000007);
+        let x = dec(false, 1, 0).subtract(&snan, RoundingMode::TiesToEven);
+        assert!(x.is_nan() && !x.is_signaling());
+        assert!(!x.is_sign_minus());
+        assert_eq!(x.0 & 0x000fffff, 7);
+    }
+
+    #[test]
+    fn multiply_preferred_exponent() {
+        // 1.00 * 1.00 = 1.0000
+        let one = dec(false, 100, -2);
+        assert_eq!(
+            parts(one.multiply(&one, RoundingMode::TiesToEven)),
+            (false, 10000, -4)
+        );
+        // -2.5 * 4 = -10.0
+        let x = dec(true, 25, -1).multiply(&dec(false, 4, 0), RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (true, 100, -1));
+        // 0 * -3E5 = -0E5
+        let x = dec(false, 0, 0).multiply(&dec(true, 3, 5), RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (true, 0, 5));
+    }
+
+    #[test]
+    fn multiply_rounds() {
+        // 1234567 * 1234567 = 1524155677489
+        let x = dec(false, 1234567, 0);
+        assert_eq!(
+            parts(x.multiply(&x, RoundingMode::TiesToEven)),
+            (false, 1524156, 6)
+        );
+        assert_eq!(
+            parts(x.multiply(&x, RoundingMode::TowardZero)),
+            (false, 1524155, 6)
+        );
+        // underflow past the smallest subnormal
+        let tiny = dec(false, 1, -60);
+        let x = tiny.multiply(&tiny, RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (false, 0, QMIN));
+        let x = tiny.multiply(&tiny, RoundingMode::TowardPositive);
+        assert_eq!(parts(x), (false, 1, QMIN));
+    }
+
+    #[test]
+    fn multiply_specials() {
+        let inf = d32::infinity(false);
+        let zero = dec(true, 0, 0);
+        assert!(inf.multiply(&zero, RoundingMode::TiesToEven).is_nan());
+        assert!(zero.multiply(&inf, RoundingMode::TiesToEven).is_nan());
+        let x = inf.multiply(&dec(true, 2, 0), RoundingMode::TiesToEven);
+        assert!(x.is_infinite() && x.is_sign_minus());
+        // first quiet nan wins between two quiet nans,
+        // but a signaling nan beats either
+        let (q1, q2, s) = (d32(0x7c000001), d32(0x7c000002), d32(0x7e000003));
+        assert_eq!(q1.multiply(&q2, RoundingMode::TiesToEven).0, 0x7c000001);
+        assert_eq!(q1.multiply(&s, RoundingMode::TiesToEven).0, 0x7c000003);
+    }
+
+    #[test]
+    fn divide_exact() {
+        // 1 / 4 = 0.25
+        let x = dec(false, 1, 0).divide(&dec(false, 4, 0), RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (false, 25, -2));
+        // 2.40 / 2 = 1.20
+        let x = dec(false, 240, -2).divide(&dec(false, 2, 0), RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (false, 120, -2));
+        // 1000 / 1E2 = 10, preferring exponent 0 - 2
+        let x = dec(false, 1000, 0).divide(&dec(false, 1, 2), RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (false, 1000, -2));
+        // -0 / 5E3 = -0E-3
+        let x = dec(true, 0, 0).divide(&dec(false, 5, 3), RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (true, 0, -3));
+    }
+
+    #[test]
+    fn divide_rounds() {
+        let one = dec(false, 1, 0);
+        let three = dec(false, 3, 0);
+        assert_eq!(
+            parts(one.divide(&three, RoundingMode::TiesToEven)),
+            (false, 3333333, -7)
+        );
+        assert_eq!(
+            parts(one.divide(&three, RoundingMode::TowardPositive)),
+            (false, 3333334, -7)
+        );
+        let two = dec(true, 2, 0);
+        assert_eq!(
+            parts(two.divide(&three, RoundingMode::TiesToEven)),
+            (true, 6666667, -7)
+        );
+        assert_eq!(
+            parts(two.divide(&three, RoundingMode::TowardZero)),
+            (true, 6666666, -7)
+        );
+    }
+
+    #[test]
+    fn divide_specials() {
+        let zero = dec(false, 0, 0);
+        let one = dec(true, 1, 0);
+        let inf = d32::infinity(false);
+        assert!(zero.divide(&zero, RoundingMode::TiesToEven).is_nan());
+        assert!(inf.divide(&inf, RoundingMode::TiesToEven).is_nan());
+        let x = one.divide(&zero, RoundingMode::TiesToEven);
+        assert!(x.is_infinite() && x.is_sign_minus());
+        let x = one.divide(&inf, RoundingMode::TiesToEven);
+        assert!(x.is_zero() && x.is_sign_minus());
+    }
+
+    #[test]
+    fn from_ratio_rounds_once() {
+        let e = RoundingMode::TiesToEven;
+        let (x, flags) = d32::from_ratio(1, 3, e);
+        assert_eq!(x.to_string(), "0.3333333");
+        assert!(flags.inexact);
+        assert_eq!(d32::from_ratio(-2, 3, e).0.to_string(), "-0.6666667");
+        assert_eq!(
+            d32::from_ratio(2, 3, RoundingMode::TowardZero)
+                .0
+                .to_string(),
+            "0.6666666"
+        );
+        // exact quotients land on the ideal exponent of zero,
+        // or as close as they can get
+        let (x, flags) = d32::from_ratio(-10, -4, e);
+        assert_eq!(parts(x), (false, 25, -1));
+        assert_eq!(flags, Flags::default());
+        assert_eq!(parts(d32::from_ratio(84, 2, e).0), (false, 42, 0));
+        assert_eq!(parts(d32::from_ratio(0, -7, e).0), (true, 0, 0));
+        // 2/19999991 is 1.00000045...E-7, which rounds down;
+        // rounding the denominator to 1.999999E+7 first would
+        // have made it 1.00000050...E-7, which rounds up
+        assert_eq!(d32::from_ratio(2, 19999991, e).0.to_string(), "1.000000E-7");
+        let two_step = d32::from(2).divide(&d32::from(19999991), e);
+        assert_eq!(two_step.to_string(), "1.000001E-7");
+        let x = d32::from_ratio(i64::MIN, 1, e).0;
+        assert_eq!(x, d32::from_i64(i64::MIN, e));
+        assert_eq!(
+            d32::from_ratio(1, i64::MIN, e).0.to_string(),
+            "-1.084202E-19"
+        );
+        // zero denominators
+        let (x, flags) = d32::from_ratio(-5, 0, e);
+        assert!(x.is_infinite() && x.is_sign_minus() && flags.div_by_zero);
+        let (x, flags) = d32::from_ratio(0, 0, e);
+        assert!(x.is_nan() && flags.invalid);
+    }
+
+    #[test]
+    fn fused_multiply_add_single_rounding() {
+        // 1234567 * 1234567 - 1524155677489 = 0 exactly,
+        // whereas rounding the product first would not be
+        let x = dec(false, 1234567, 0);
+        let z = dec(true, 1524155, 6);
+        let fma = x.fused_multiply_add(&x, &z, RoundingMode::TiesToEven);
+        assert_eq!(parts(fma), (false, 677489, 0));
+        let two_step = x
+            .multiply(&x, RoundingMode::TiesToEven)
+            .add(&z, RoundingMode::TiesToEven);
+        assert_eq!(parts(two_step), (false, 1, 6));
+        // 2.5 * 2 + 0.01 = 5.01
+        let fma = dec(false, 25, -1).fused_multiply_add(
+            &dec(false, 2, 0),
+            &dec(false, 1, -2),
+            RoundingMode::TiesToEven,
+        );
+        assert_eq!(parts(fma), (false, 501, -2));
+    }
+
+    #[test]
+    fn fused_multiply_add_zero_sign() {
+        // 3 * -2 + 6 is exactly zero
+        let (a, b, c) = (dec(false, 3, 0), dec(true, 2, 0), dec(false, 6, 0));
+        let fma = a.fused_multiply_add(&b, &c, RoundingMode::TiesToEven);
+        assert!(fma.is_zero() && !fma.is_sign_minus());
+        let fma = a.fused_multiply_add(&b, &c, RoundingMode::TowardNegative);
+        assert!(fma.is_zero() && fma.is_sign_minus());
+    }
+
+    #[test]
+    fn fused_multiply_add_specials() {
+        let inf = d32::infinity(false);
+        let zero = dec(false, 0, 0);
+        let one = dec(false, 1, 0);
+        assert!(zero
+            .fused_multiply_add(&inf, &one, RoundingMode::TiesToEven)
+            .is_nan());
+        assert!(inf
+            .fused_multiply_add(&one, &d32::infinity(true), RoundingMode::TiesToEven)
+            .is_nan());
+        let x = one.fused_multiply_add(&one, &d32::infinity(true), RoundingMode::TiesToEven);
+        assert!(x.is_infinite() && x.is_sign_minus());
+        assert_eq!(
+            one.fused_multiply_add(&one, &d32(0x7e000009), RoundingMode::TiesToEven)
+                .0,
+            0x7c000009
+        );
+    }
+
+    #[test]
+    fn sqrt_exact() {
+        let x = dec(false, 4, 0).sqrt(RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (false, 2, 0));
+        let x = dec(false, 25, -2).sqrt(RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (false, 5, -1));
+        let x = dec(false, 100, 0).sqrt(RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (false, 10, 0));
+        let x = dec(false, 1, 2).sqrt(RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (false, 1, 1));
+        // odd exponents floor: sqrt(4.0) has ideal exponent -1
+        let x = dec(false, 40, -1).sqrt(RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (false, 20, -1));
+        let x = dec(false, 9, -1).sqrt(RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (false, 9486833, -7));
+    }
+
+    #[test]
+    fn sqrt_rounds() {
+        let two = dec(false, 2, 0);
+        // sqrt(2) = 1.41421356...
+        assert_eq!(
+            parts(two.sqrt(RoundingMode::TiesToEven)),
+            (false, 1414214, -6)
+        );
+        assert_eq!(
+            parts(two.sqrt(RoundingMode::TiesToAway)),
+            (false, 1414214, -6)
+        );
+        assert_eq!(
+            parts(two.sqrt(RoundingMode::TowardZero)),
+            (false, 1414213, -6)
+        );
+        assert_eq!(
+            parts(two.sqrt(RoundingMode::TowardNegative)),
+            (false, 1414213, -6)
+        );
+        assert_eq!(
+            parts(two.sqrt(RoundingMode::TowardPositive)),
+            (false, 1414214, -6)
+        );
+    }
+
+    #[test]
+    fn sqrt_specials() {
+        let x = dec(true, 0, 4).sqrt(RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (true, 0, 2));
+        assert!(dec(true, 1, 0).sqrt(RoundingMode::TiesToEven).is_nan());
+        assert!(d32::infinity(true).sqrt(RoundingMode::TiesToEven).is_nan());
+        let x = d32::infinity(false).sqrt(RoundingMode::TiesToEven);
+        assert!(x.is_infinite() && !x.is_sign_minus());
+    }
+
+    #[test]
+    fn exp_rounds() {
+        // e = 2.718281828...
+        let one = dec(false, 1, 0);
+        assert_eq!(
+            parts(one.exp(RoundingMode::TiesToEven)),
+            (false, 2718282, -6)
+        );
+        assert_eq!(
+            parts(one.exp(RoundingMode::TowardZero)),
+            (false, 2718281, -6)
+        );
+        let (x, flags) = one.exp_with_flags(RoundingMode::TiesToEven);
+        assert!(x.is_finite() && flags.inexact && !flags.underflow);
+        // e^-1 = 0.3678794411..., e^10 = 22026.46579...
+        assert_eq!(
+            parts(dec(true, 1, 0).exp(RoundingMode::TiesToEven)),
+            (false, 3678794, -7)
+        );
+        assert_eq!(
+            parts(dec(false, 10, 0).exp(RoundingMode::TiesToEven)),
+            (false, 2202647, -2)
+        );
+        // e^2.302585 = 9.99999906...
+        assert_eq!(
+            parts(dec(false, 2302585, -6).exp(RoundingMode::TowardZero)),
+            (false, 9999999, -6)
+        );
+        assert_eq!(
+            parts(dec(false, 2302585, -6).exp(RoundingMode::TowardPositive)),
+            (false, 1000000, -5)
+        );
+        // e^-230 = 1.3336e-100 is subnormal
+        let (x, flags) = dec(true, 230, 0).exp_with_flags(RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (false, 13, -101));
+        assert!(flags.underflow && flags.inexact);
+    }
+
+    #[test]
+    fn exp_matches_f64() {
+        // f64 is good to about 16 digits, so away from ties the
+        // two should agree
+        let mut x = dec(true, 2345678, -4);
+        while x.to_f64() < 220.0 {
+            let expected = d32::from_f64(x.to_f64().exp(), RoundingMode::TiesToEven);
+            let got = x.exp(RoundingMode::TiesToEven);
+            assert!(
+                got == expected || got == expected.next_up() || got == expected.next_down(),
+                "{}: {} vs {}",
+                x,
+                got,
+                expected
+            );
+            x = x.add(&dec(false, 1234567, -7), RoundingMode::TiesToEven);
+        }
+    }
+
+    #[test]
+    fn exp_specials() {
+        let x = d32::NEG_INFINITY.exp(RoundingMode::TiesToEven);
+        assert_eq!(x.to_bits(), d32::ZERO.to_bits());
+        let x = d32::INFINITY.exp(RoundingMode::TiesToEven);
+        assert_eq!(x.to_bits(), d32::INFINITY.to_bits());
+        let (x, flags) = dec(true, 0, 5).exp_with_flags(RoundingMode::TiesToEven);
+        assert_eq!(x.to_bits(), d32::ONE.to_bits());
+        assert_eq!(flags, Flags::default());
+        assert!(d32::NAN.exp(RoundingMode::TiesToEven).is_nan());
+        let (x, flags) = d32::signaling_nan(false, 7).exp_with_flags(RoundingMode::TiesToEven);
+        assert_eq!(x.payload(), Some(7));
+        assert!(!x.is_signaling() && flags.invalid);
+
+        // tiny arguments land just to one side of one
+        let tiny = dec(false, 1, -30);
+        assert_eq!(
+            parts(tiny.exp(RoundingMode::TiesToEven)),
+            (false, 1000000, -6)
+        );
+        assert_eq!(
+            parts(tiny.exp(RoundingMode::TowardPositive)),
+            (false, 1000001, -6)
+        );
+        assert_eq!(
+            parts(tiny.negate().exp(RoundingMode::TowardNegative)),
+            (false, 9999999, -7)
+        );
+
+        let (x, flags) = dec(false, 230, 0).exp_with_flags(RoundingMode::TiesToEven);
+        assert!(x.is_infinite() && flags.overflow);
+        let x = dec(false, 1, 5).exp(RoundingMode::TowardZero);
+        assert_eq!(x.to_bits(), d32::MAX.to_bits());
+        let (x, flags) = dec(true, 1, 5).exp_with_flags(RoundingMode::TiesToEven);
+        assert!(x.is_zero() && flags.underflow);
+        let x = dec(true, 1, 5).exp(RoundingMode::TowardPositive);
+        assert_eq!(parts(x), (false, 1, QMIN));
+    }
+
+    #[test]
+    fn ln_rounds() {
+        // ln 10 = 2.302585093, ln 2 = 0.6931471806
+        assert_eq!(
+            parts(dec(false, 10, 0).ln(RoundingMode::TiesToEven)),
+            (false, 2302585, -6)
+        );
+        assert_eq!(
+            parts(dec(false, 2, 0).ln(RoundingMode::TiesToEven)),
+            (false, 6931472, -7)
+        );
+        assert_eq!(
+            parts(dec(false, 5, -1).ln(RoundingMode::TowardZero)),
+            (true, 6931471, -7)
+        );
+        // ln 0.9999999 = -1.00000005e-7
+        let (x, flags) = dec(false, 9999999, -7).ln_with_flags(RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (true, 1000000, -13));
+        assert!(flags.inexact);
+        // ln(9.999999e96) = 223.35075, ln(1e-101) = -232.56109
+        assert_eq!(
+            parts(d32::MAX.ln(RoundingMode::TiesToEven)),
+            (false, 2233508, -4)
+        );
+        assert_eq!(
+            parts(dec(false, 1, QMIN).ln(RoundingMode::TiesToEven)),
+            (true, 2325611, -4)
+        );
+    }
+
+    #[test]
+    fn ln_matches_f64() {
+        let mut x = dec(false, 1234567, -40);
+        while x.is_finite() {
+            let expected = d32::from_f64(x.to_f64().ln(), RoundingMode::TiesToEven);
+            let got = x.ln(RoundingMode::TiesToEven);
+            assert!(
+                got == expected || got == expected.next_up() || got == expected.next_down(),
+                "{}: {} vs {}",
+                x,
+                got,
+                expected
+            );
+            x = x.multiply(&dec(false, 1234567, -6), RoundingMode::TiesToEven);
+        }
+    }
+
+    #[test]
+    fn ln_specials() {
+        let (x, flags) = dec(false, 1000, -3).ln_with_flags(RoundingMode::TowardNegative);
+        assert_eq!(x.to_bits(), d32::ZERO.to_bits());
+        assert_eq!(flags, Flags::default());
+        let (x, flags) = dec(true, 0, 3).ln_with_flags(RoundingMode::TiesToEven);
+        assert_eq!(x.to_bits(), d32::NEG_INFINITY.to_bits());
+        assert!(flags.div_by_zero && !flags.invalid);
+        let (x, flags) = dec(true, 1, 0).ln_with_flags(RoundingMode::TiesToEven);
+        assert!(x.is_nan() && flags.invalid);
+        assert!(d32::NEG_INFINITY.ln(RoundingMode::TiesToEven).is_nan());
+        let x = d32::INFINITY.ln(RoundingMode::TiesToEven);
+        assert_eq!(x.to_bits(), d32::INFINITY.to_bits());
+        let (x, flags) = d32::signaling_nan(true, 3).ln_with_flags(RoundingMode::TiesToEven);
+        assert!(x.is_nan() && !x.is_signaling() && flags.invalid);
+    }
+
+    #[test]
+    fn log10_powers_of_ten_are_exact() {
+        for (x, expected) in [
+            (dec(false, 1000, 0), (false, 3, 0)),
+            (dec(false, 1, 3), (false, 3, 0)),
+            (dec(false, 100, -1), (false, 1, 0)),
+            (dec(false, 1, -50), (true, 50, 0)),
+            (dec(false, 1000000, QMIN), (true, 95, 0)),
+            (dec(false, 1, QMIN), (true, 101, 0)),
+            (dec(false, 1000000, QMAX), (false, 96, 0)),
+        ] {
+            let (y, flags) = x.log10_with_flags(RoundingMode::TowardPositive);
+            assert_eq!(parts(y), expected, "{}", x);
+            assert_eq!(flags, Flags::default());
+        }
+        let x = dec(false, 10, -1).log10(RoundingMode::TowardNegative);
+        assert_eq!(x.to_bits(), d32::ZERO.to_bits());
+    }
+
+    #[test]
+    fn log10_rounds() {
+        // log10 2 = 0.30102999566
+        assert_eq!(
+            parts(dec(false, 2, 0).log10(RoundingMode::TiesToEven)),
+            (false, 3010300, -7)
+        );
+        assert_eq!(
+            parts(dec(false, 2, 0).log10(RoundingMode::TowardZero)),
+            (false, 3010299, -7)
+        );
+        // log10(9.999999e96) = 96.99999996
+        assert_eq!(
+            parts(d32::MAX.log10(RoundingMode::TiesToEven)),
+            (false, 9700000, -5)
+        );
+        assert_eq!(
+            parts(d32::MAX.log10(RoundingMode::TowardZero)),
+            (false, 9699999, -5)
+        );
+        // log10 1.000001 = 4.342942647e-7
+        assert_eq!(
+            parts(dec(false, 1000001, -6).log10(RoundingMode::TiesToEven)),
+            (false, 4342943, -13)
+        );
+        let (x, flags) = dec(false, 0, 0).log10_with_flags(RoundingMode::TiesToEven);
+        assert!(x.is_infinite() && x.is_sign_minus() && flags.div_by_zero);
+        assert!(dec(true, 2, 0).log10(RoundingMode::TiesToEven).is_nan());
+    }
+
+    #[test]
+    fn pown_exact() {
+        let (x, flags) = dec(false, 2, 0).pown_with_flags(10, RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (false, 1024, 0));
+        assert_eq!(flags, Flags::default());
+        let x = dec(false, 15, -1).pown(2, RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (false, 225, -2));
+        let x = dec(true, 2, 0).pown(3, RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (true, 8, 0));
+        let x = dec(true, 2, 0).pown(4, RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (false, 16, 0));
+        let (x, flags) = dec(false, 2, 0).pown_with_flags(-1, RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (false, 5, -1));
+        assert_eq!(flags, Flags::default());
+        let x = dec(false, 4, -1).pown(-2, RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (false, 625, -2));
+        // trailing zeros come back as far as the precision
+        // allows, however big the power
+        let x = dec(false, 10, -1).pown(1000, RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (false, 1000000, -6));
+        let (x, flags) = dec(false, 1000000, 0).pown_with_flags(7, RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (false, 1000000, 36));
+        assert_eq!(flags, Flags::default());
+        let (x, flags) = dec(false, 1, 6).pown_with_flags(7, RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (false, 1, 42));
+        assert_eq!(flags, Flags::default());
+        // and don't get in the way of exact reciprocals
+        let (x, flags) = parse("50.0").pown_with_flags(-7, RoundingMode::TowardNegative);
+        assert_eq!(parts(x), (false, 128, -14));
+        assert_eq!(flags, Flags::default());
+        let (x, flags) = dec(false, 1000, 0).pown_with_flags(-7, RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (false, 1, -21));
+        assert_eq!(flags, Flags::default());
+        let (x, flags) = dec(false, 10, 0).pown_with_flags(-20, RoundingMode::TowardPositive);
+        assert_eq!(parts(x), (false, 1, -20));
+        assert_eq!(flags, Flags::default());
+        // 1 / 2^40 = 9.094947017729282379150390625E-13, but rounded
+        let (x, flags) = dec(false, 2, 0).pown_with_flags(-40, RoundingMode::TowardZero);
+        assert_eq!(parts(x), (false, 9094947, -19));
+        assert!(flags.inexact);
+    }
+
+    #[test]
+    fn pown_rounds() {
+        // 2^30 = 1073741824
+        let (x, flags) = dec(false, 2, 0).pown_with_flags(30, RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (false, 1073742, 3));
+        assert!(flags.inexact);
+        let x = dec(false, 3, 0).pown(-1, RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (false, 3333333, -7));
+        // 1.5^-7 = 0.05852766346...
+        let x = dec(false, 15, -1).pown(-7, RoundingMode::TowardPositive);
+        assert_eq!(parts(x), (false, 5852767, -8));
+        // past 128 bits: 7^50 = 1.798465042647e42
+        let x = dec(false, 7, 0).pown(50, RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (false, 1798465, 36));
+        // 1.000001^1000000 = 2.718280469...
+        let x = dec(false, 1000001, -6).pown(1000000, RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (false, 2718280, -6));
+        // 2^-400 = 3.87e-121 underflows; 10^97 overflows
+        let (x, flags) = dec(false, 2, 0).pown_with_flags(-400, RoundingMode::TiesToEven);
+        assert!(x.is_zero() && flags.underflow);
+        let (x, flags) = dec(false, 1, 1).pown_with_flags(97, RoundingMode::TiesToEven);
+        assert!(x.is_infinite() && flags.overflow);
+    }
+
+    #[test]
+    fn pown_specials() {
+        let (x, flags) = dec(false, 0, 0).pown_with_flags(-1, RoundingMode::TiesToEven);
+        assert_eq!(x.to_bits(), d32::INFINITY.to_bits());
+        assert!(flags.div_by_zero);
+        let x = dec(true, 0, 0).pown(-3, RoundingMode::TiesToEven);
+        assert_eq!(x.to_bits(), d32::NEG_INFINITY.to_bits());
+        let x = dec(true, 0, 0).pown(2, RoundingMode::TiesToEven);
+        assert_eq!(x.to_bits(), d32::ZERO.to_bits());
+        let x = d32::NEG_INFINITY.pown(3, RoundingMode::TiesToEven);
+        assert_eq!(x.to_bits(), d32::NEG_INFINITY.to_bits());
+        let (x, flags) = d32::NEG_INFINITY.pown_with_flags(-3, RoundingMode::TiesToEven);
+        assert_eq!(x.to_bits(), d32::NEG_ZERO.to_bits());
+        assert_eq!(flags, Flags::default());
+        let x = d32::NAN.pown(0, RoundingMode::TiesToEven);
+        assert_eq!(x.to_bits(), d32::ONE.to_bits());
+        let (x, flags) = d32::signaling_nan(false, 0).pown_with_flags(0, RoundingMode::TiesToEven);
+        assert!(x.is_nan() && flags.invalid);
+        assert!(d32::NAN.pown(2, RoundingMode::TiesToEven).is_nan());
+    }
+
+    #[test]
+    fn pow_rounds() {
+        let two = dec(false, 2, 0);
+        let half = dec(false, 5, -1);
+        assert_eq!(
+            parts(two.pow(&half, RoundingMode::TiesToEven)),
+            (false, 1414214, -6)
+        );
+        assert_eq!(
+            parts(two.pow(&half, RoundingMode::TowardZero)),
+            (false, 1414213, -6)
+        );
+        assert_eq!(
+            parts(dec(false, 10, 0).pow(&dec(false, 25, -1), RoundingMode::TiesToEven)),
+            (false, 3162278, -4)
+        );
+        // 0.9999999^-1e9 = 2.688131e43
+        let (x, flags) =
+            dec(false, 9999999, -7).pow_with_flags(&dec(true, 1, 9), RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (false, 2688131, 37));
+        assert!(flags.inexact);
+        // integer exponents are handed to pown
+        let (x, flags) =
+            dec(true, 2, 0).pow_with_flags(&dec(false, 30, -1), RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (true, 8, 0));
+        assert_eq!(flags, Flags::default());
+        let (x, flags) =
+            dec(false, 2, 0).pow_with_flags(&dec(false, 1, 3), RoundingMode::TiesToEven);
+        assert!(x.is_infinite() && flags.overflow);
+        let x = dec(false, 2, 0).pow(&dec(true, 1, 3), RoundingMode::TowardPositive);
+        assert_eq!(parts(x), (false, 1, QMIN));
+        // integers past an i32 are all even
+        let x = dec(true, 1, 0).pow(&dec(false, 1, 10), RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (false, 1, 0));
+        let x = dec(true, 1000001, -6).pow(&dec(false, 1, 10), RoundingMode::TiesToEven);
+        assert!(x.is_infinite() && !x.is_sign_minus());
+        // a tiny y still lands on the right side of one
+        let (two, half) = (dec(false, 2, 0), dec(false, 5, -1));
+        let tiny = dec(false, 1, -50);
+        let (x, flags) = two.pow_with_flags(&tiny.negate(), RoundingMode::TowardPositive);
+        assert_eq!(parts(x), (false, 1000000, -6));
+        assert!(flags.inexact);
+        let x = two.pow(&tiny.negate(), RoundingMode::TowardZero);
+        assert_eq!(parts(x), (false, 9999999, -7));
+        let x = half.pow(&tiny, RoundingMode::TowardPositive);
+        assert_eq!(parts(x), (false, 1000000, -6));
+        let x = half.pow(&tiny, RoundingMode::TowardNegative);
+        assert_eq!(parts(x), (false, 9999999, -7));
+        let x = two.pow(&tiny, RoundingMode::TowardPositive);
+        assert_eq!(parts(x), (false, 1000001, -6));
+    }
+
+    #[test]
+    fn pow_exact_fractions() {
+        let e = RoundingMode::TiesToEven;
+        let cases = [
+            ("4", "0.5", (false, 2, 0)),
+            ("100", "1.5", (false, 1000, 0)),
+            ("1024", "0.3", (false, 8, 0)),
+            ("1024", "1.70", (false, 131072, 0)),
+            ("0.25", "-0.5", (false, 2, 0)),
+            ("1E-100", "0.25", (false, 1, -25)),
+            ("6.25", "0.5", (false, 25, -1)),
+        ];
+        for &(x, y, expected) in cases.iter() {
+            let (z, flags) = parse(x).pow_with_flags(&parse(y), e);
+            assert_eq!(parts(z), expected, "{}^{}", x, y);
+            assert_eq!(flags, Flags::default(), "{}^{}", x, y);
+        }
+        // an exact root, but a power of it that needs rounding
+        let (z, flags) = dec(false, 4, 0).pow_with_flags(&parse("15.5"), e);
+        assert_eq!(parts(z), (false, 2147484, 3));
+        assert!(flags.inexact);
+        // and no exact root at all
+        let (z, flags) = dec(false, 2, 0).pow_with_flags(&parse("0.5"), e);
+        assert_eq!(parts(z), (false, 1414214, -6));
+        assert!(flags.inexact);
+    }
+
+    #[test]
+    fn pow_specials() {
+        let one = d32::ONE.to_bits();
+        let e = RoundingMode::TiesToEven;
+        assert_eq!(dec(false, 0, 0).pow(&dec(false, 0, 0), e).to_bits(), one);
+        assert_eq!(d32::NAN.pow(&dec(true, 0, 3), e).to_bits(), one);
+        assert_eq!(dec(false, 10, -1).pow(&d32::NAN, e).to_bits(), one);
+        assert_eq!(dec(true, 1, 0).pow(&d32::INFINITY, e).to_bits(), one);
+        assert_eq!(dec(true, 1, 0).pow(&d32::NEG_INFINITY, e).to_bits(), one);
+        let (x, flags) = d32::ONE.pow_with_flags(&d32::signaling_nan(false, 0), e);
+        assert!(x.is_nan() && flags.invalid);
+        assert!(dec(false, 2, 0).pow(&d32::NAN, e).is_nan());
+
+        // negative bases need integer exponents
+        let (x, flags) = dec(true, 8, 0).pow_with_flags(&dec(false, 3333333, -7), e);
+        assert!(x.is_nan() && flags.invalid);
+        let x = d32::NEG_INFINITY.pow(&dec(false, 5, -1), e);
+        assert_eq!(x.to_bits(), d32::INFINITY.to_bits());
+
+        let half = dec(false, 5, -1);
+        assert_eq!(half.pow(&d32::INFINITY, e).to_bits(), d32::ZERO.to_bits());
+        assert_eq!(
+            half.pow(&d32::NEG_INFINITY, e).to_bits(),
+            d32::INFINITY.to_bits()
+        );
+        assert_eq!(
+            dec(true, 2, 0).pow(&d32::INFINITY, e).to_bits(),
+            d32::INFINITY.to_bits()
+        );
+        assert_eq!(
+            d32::INFINITY.pow(&half.negate(), e).to_bits(),
+            d32::ZERO.to_bits()
+        );
+
+        let (x, flags) = dec(false, 0, 0).pow_with_flags(&dec(true, 1, 0), e);
+        assert_eq!(x.to_bits(), d32::INFINITY.to_bits());
+        assert!(flags.div_by_zero);
+        let x = dec(true, 0, 0).pow(&dec(true, 1, 0), e);
+        assert_eq!(x.to_bits(), d32::NEG_INFINITY.to_bits());
+        let (x, flags) = dec(true, 0, 0).pow_with_flags(&half.negate(), e);
+        assert_eq!(x.to_bits(), d32::INFINITY.to_bits());
+        assert!(flags.div_by_zero);
+        let (x, flags) = dec(true, 0, 0).pow_with_flags(&half, e);
+        assert_eq!(x.to_bits(), d32::ZERO.to_bits());
+        assert_eq!(flags, Flags::default());
+        let x = dec(true, 0, 0).pow(&dec(false, 3, 0), e);
+        assert_eq!(x.to_bits(), d32::NEG_ZERO.to_bits());
+        let x = dec(false, 0, 0).pow(&d32::NEG_INFINITY, e);
+        assert_eq!(x.to_bits(), d32::INFINITY.to_bits());
+    }
+
+    #[test]
+    fn hypot_exact() {
+        let e = RoundingMode::TiesToEven;
+        let (x, flags) = dec(false, 3, 0).hypot_with_flags(&dec(true, 4, 0), e);
+        assert_eq!(parts(x), (false, 5, 0));
+        assert_eq!(flags, Flags::default());
+        let x = dec(false, 30, -1).hypot(&dec(false, 4, 0), e);
+        assert_eq!(parts(x), (false, 50, -1));
+        // squaring 3e90 directly would overflow, 3e-60 underflow
+        let x = dec(false, 3, 90).hypot(&dec(false, 4, 90), e);
+        assert_eq!(parts(x), (false, 5, 90));
+        let x = dec(false, 3, -60).hypot(&dec(false, 4, -60), e);
+        assert_eq!(parts(x), (false, 5, -60));
+        let x = dec(true, 3, 0).hypot(&dec(false, 0, -5), e);
+        assert_eq!(parts(x), (false, 3, 0));
+        let x = dec(true, 0, 2).hypot(&dec(true, 0, -5), e);
+        assert_eq!(parts(x), (false, 0, -5));
+    }
+
+    #[test]
+    fn hypot_rounds() {
+        let one = dec(false, 1, 0);
+        assert_eq!(
+            parts(one.hypot(&one, RoundingMode::TiesToEven)),
+            (false, 1414214, -6)
+        );
+        assert_eq!(
+            parts(one.hypot(&one, RoundingMode::TowardZero)),
+            (false, 1414213, -6)
+        );
+        let tiny = dec(false, 1, -20);
+        assert_eq!(
+            parts(one.hypot(&tiny, RoundingMode::TiesToEven)),
+            (false, 1000000, -6)
+        );
+        assert_eq!(
+            parts(tiny.hypot(&one, RoundingMode::TowardPositive)),
+            (false, 1000001, -6)
+        );
+        let (x, flags) = d32::MAX.hypot_with_flags(&d32::MAX, RoundingMode::TiesToEven);
+        assert!(x.is_infinite() && flags.overflow);
+        let smallest = dec(false, 1, QMIN);
+        let (x, flags) = smallest.hypot_with_flags(&smallest, RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (false, 1, QMIN));
+        assert!(flags.underflow && flags.inexact);
+    }
+
+    #[test]
+    fn hypot_specials() {
+        let e = RoundingMode::TiesToEven;
+        let x = d32::NEG_INFINITY.hypot(&d32::NAN, e);
+        assert_eq!(x.to_bits(), d32::INFINITY.to_bits());
+        let x = d32::NAN.hypot(&d32::NEG_INFINITY, e);
+        assert_eq!(x.to_bits(), d32::INFINITY.to_bits());
+        let (x, flags) = d32::signaling_nan(false, 0).hypot_with_flags(&d32::INFINITY, e);
+        assert!(x.is_nan() && flags.invalid);
+        assert!(d32::NAN.hypot(&d32::ONE, e).is_nan());
+    }
+
+    #[test]
+    fn compound_keeps_one_plus_x_exact() {
+        let e = RoundingMode::TiesToEven;
+        let rate = dec(false, 5, -2);
+        let (x, flags) = rate.compound_with_flags(2, e);
+        assert_eq!(parts(x), (false, 11025, -4));
+        assert_eq!(flags, Flags::default());
+        // 1.05^10 = 1.628894627
+        assert_eq!(parts(rate.compound(10, e)), (false, 1628895, -6));
+        // 1.0000001 isn't a d32, but (1 + 1e-7)^1e7 = 2.718281692
+        let x = dec(false, 1, -7).compound(10000000, e);
+        assert_eq!(parts(x), (false, 2718282, -6));
+        let x = dec(false, 1, -7).compound(1, RoundingMode::TowardPositive);
+        assert_eq!(parts(x), (false, 1000001, -6));
+        // 1.01^-12 = 0.88744923
+        let x = dec(false, 1, -2).compound(-12, e);
+        assert_eq!(parts(x), (false, 8874492, -7));
+        // 0.75^-2 = 1.7777...
+        let x = dec(true, 25, -2).compound(-2, e);
+        assert_eq!(parts(x), (false, 1777778, -6));
+        let x = dec(false, 1, -30).compound(5, RoundingMode::TowardPositive);
+        assert_eq!(parts(x), (false, 1000001, -6));
+        let x = dec(false, 1, -30).compound(-5, RoundingMode::TowardNegative);
+        assert_eq!(parts(x), (false, 9999999, -7));
+        let x = d32::MAX.compound(1, RoundingMode::TiesToEven);
+        assert_eq!(x.to_bits(), d32::MAX.to_bits());
+        let (x, flags) = d32::MAX.compound_with_flags(1, RoundingMode::TowardPositive);
+        assert!(x.is_infinite() && flags.overflow);
+        // 1 / (2.5E+88 + 1) is just under 4E-89
+        let big = dec(false, 25, 87);
+        let (x, flags) = big.compound_with_flags(-1, RoundingMode::TowardNegative);
+        assert_eq!(parts(x), (false, 3999999, -95));
+        assert!(flags.inexact);
+        let x = big.compound(-1, RoundingMode::TowardPositive);
+        assert_eq!(parts(x), (false, 4000000, -95));
+        let x = big.compound(-1, RoundingMode::TowardZero);
+        assert_eq!(parts(x), (false, 3999999, -95));
+        let x = big.compound(-1, RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (false, 4000000, -95));
+        // and its square's just under 1.6E-89
+        let x = dec(false, 25, 43).compound(-2, RoundingMode::TowardNegative);
+        assert_eq!(parts(x), (false, 1599999, -95));
+        let x = dec(false, 25, 43).compound(-2, RoundingMode::TowardPositive);
+        assert_eq!(parts(x), (false, 1600000, -95));
+    }
+
+    #[test]
+    fn compound_specials() {
+        let e = RoundingMode::TiesToEven;
+        let minus_one = dec(true, 1, 0);
+        let (x, flags) = minus_one.compound_with_flags(-1, e);
+        assert_eq!(x.to_bits(), d32::INFINITY.to_bits());
+        assert!(flags.div_by_zero);
+        assert_eq!(minus_one.compound(3, e).to_bits(), d32::ZERO.to_bits());
+        let (x, flags) = dec(true, 2, 0).compound_with_flags(2, e);
+        assert!(x.is_nan() && flags.invalid);
+        assert!(d32::NEG_INFINITY.compound(0, e).is_nan());
+        assert_eq!(d32::NAN.compound(0, e).to_bits(), d32::ONE.to_bits());
+        assert!(d32::NAN.compound(1, e).is_nan());
+        let (x, flags) = d32::signaling_nan(false, 0).compound_with_flags(0, e);
+        assert!(x.is_nan() && flags.invalid);
+        assert_eq!(
+            d32::INFINITY.compound(2, e).to_bits(),
+            d32::INFINITY.to_bits()
+        );
+        assert_eq!(d32::INFINITY.compound(-2, e).to_bits(), d32::ZERO.to_bits());
+        assert_eq!(
+            dec(true, 0, 0).compound(-7, e).to_bits(),
+            d32::ONE.to_bits()
+        );
+    }
+
+    #[test]
+    fn rootn_exact() {
+        let e = RoundingMode::TiesToEven;
+        let (x, flags) = dec(false, 8, 0).rootn_with_flags(3, e);
+        assert_eq!(parts(x), (false, 2, 0));
+        assert_eq!(flags, Flags::default());
+        assert_eq!(parts(dec(true, 8, 0).rootn(3, e)), (true, 2, 0));
+        assert_eq!(parts(dec(false, 1000, 0).rootn(3, e)), (false, 10, 0));
+        assert_eq!(parts(dec(false, 8, -3).rootn(3, e)), (false, 2, -1));
+        assert_eq!(parts(dec(false, 1, -99).rootn(3, e)), (false, 1, -33));
+        assert_eq!(parts(dec(false, 1024, 0).rootn(10, e)), (false, 2, 0));
+        let (x, flags) = dec(false, 8, 0).rootn_with_flags(-3, e);
+        assert_eq!(parts(x), (false, 5, -1));
+        assert_eq!(flags, Flags::default());
+        assert_eq!(d32::MAX.rootn(1, e).to_bits(), d32::MAX.to_bits());
+    }
+
+    #[test]
+    fn rootn_rounds() {
+        // 2^(1/12) = 1.0594630943...
+        let two = dec(false, 2, 0);
+        assert_eq!(
+            parts(two.rootn(12, RoundingMode::TiesToEven)),
+            (false, 1059463, -6)
+        );
+        assert_eq!(
+            parts(two.rootn(2, RoundingMode::TowardPositive)),
+            (false, 1414214, -6)
+        );
+        assert_eq!(
+            parts(two.rootn(-2, RoundingMode::TiesToEven)),
+            (false, 7071068, -7)
+        );
+        // 8e-2^(1/3) = 0.4308869380
+        let (x, flags) = dec(false, 8, -2).rootn_with_flags(3, RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (false, 4308869, -7));
+        assert!(flags.inexact);
+    }
+
+    #[test]
+    fn rootn_specials() {
+        let e = RoundingMode::TiesToEven;
+        assert!(dec(false, 2, 0).rootn(0, e).is_nan());
+        let (x, flags) = dec(true, 4, 0).rootn_with_flags(2, e);
+        assert!(x.is_nan() && flags.invalid);
+        assert!(d32::NEG_INFINITY.rootn(2, e).is_nan());
+        assert_eq!(
+            d32::NEG_INFINITY.rootn(3, e).to_bits(),
+            d32::NEG_INFINITY.to_bits()
+        );
+        assert_eq!(
+            d32::NEG_INFINITY.rootn(-3, e).to_bits(),
+            d32::NEG_ZERO.to_bits()
+        );
+        assert_eq!(d32::INFINITY.rootn(-2, e).to_bits(), d32::ZERO.to_bits());
+        assert_eq!(dec(true, 0, 0).rootn(2, e).to_bits(), d32::ZERO.to_bits());
+        let (x, flags) = dec(true, 0, 0).rootn_with_flags(-3, e);
+        assert_eq!(x.to_bits(), d32::NEG_INFINITY.to_bits());
+        assert!(flags.div_by_zero);
+        let (x, flags) = dec(true, 0, 0).rootn_with_flags(-2, e);
+        assert_eq!(x.to_bits(), d32::INFINITY.to_bits());
+        assert!(flags.div_by_zero);
+    }
+
+    #[test]
+    fn arithmetic_flags() {
+        let one = dec(false, 1, 0);
+        let three = dec(false, 3, 0);
+        let (_, flags) = one.divide_with_flags(&three, RoundingMode::TiesToEven);
+        assert_eq!(
+            flags,
+            Flags {
+                inexact: true,
+                ..Flags::default()
+            }
+        );
+        let (_, flags) = one.add_with_flags(&three, RoundingMode::TiesToEven);
+        assert_eq!(flags, Flags::default());
+        let (x, flags) = one.divide_with_flags(&dec(true, 0, 0), RoundingMode::TiesToEven);
+        assert!(x.is_infinite() && x.is_sign_minus());
+        assert_eq!(
+            flags,
+            Flags {
+                div_by_zero: true,
+                ..Flags::default()
+            }
+        );
+        let (_, flags) = d32(0x7e000000).multiply_with_flags(&one, RoundingMode::TiesToEven);
+        assert_eq!(
+            flags,
+            Flags {
+                invalid: true,
+                ..Flags::default()
+            }
+        );
+        let (_, flags) = d32(0x7c000000).multiply_with_flags(&one, RoundingMode::TiesToEven);
+        assert_eq!(flags, Flags::default());
+        let (_, flags) = dec(true, 4, 0).sqrt_with_flags(RoundingMode::TiesToEven);
+        assert!(flags.invalid);
+        let (_, flags) = d32(0x7c000000).fused_multiply_add_with_flags(
+            &one,
+            &d32(0x7e000000),
+            RoundingMode::TiesToEven,
+        );
+        assert!(flags.invalid);
+    }
+
+    #[test]
+    fn arithmetic_range_flags() {
+        let big = dec(false, 5, QMAX);
+        let (x, flags) = big.multiply_with_flags(&big, RoundingMode::TiesToEven);
+        assert!(x.is_infinite());
+        assert_eq!(
+            flags,
+            Flags {
+                overflow: true,
+                inexact: true,
+                ..Flags::default()
+            }
+        );
+        let tiny = dec(false, 3, -60);
+        let (x, flags) = tiny.multiply_with_flags(&tiny, RoundingMode::TiesToEven);
+        assert!(x.is_zero());
+        assert_eq!(
+            flags,
+            Flags {
+                underflow: true,
+                inexact: true,
+                ..Flags::default()
+            }
+        );
+        // exact subnormal results don't underflow
+        let (_, flags) =
+            dec(false, 4, -50).multiply_with_flags(&dec(false, 2, -50), RoundingMode::TiesToEven);
+        assert_eq!(flags, Flags::default());
+    }
+
+    #[test]
+    fn finalize_overflow_by_mode() {
+        // 9.9999995e96 is below 1e97 but rounds up past max
+        let (x, flags) = d32::finalize(false, 99999995, 89, RoundingMode::TiesToEven);
+        assert!(x.is_infinite() && flags.overflow && flags.inexact);
+        let x = d32::finalize(false, 99999995, 89, RoundingMode::TiesToAway).0;
+        assert!(x.is_infinite());
+        let cases = [
+            (false, RoundingMode::TowardZero, false),
+            (false, RoundingMode::TowardNegative, false),
+            (false, RoundingMode::TowardPositive, true),
+            (true, RoundingMode::TowardZero, false),
+            (true, RoundingMode::TowardNegative, true),
+            (true, RoundingMode::TowardPositive, false),
+        ];
+        for (sign, mode, infinite) in cases {
+            let (x, flags) = d32::finalize(sign, 1, 97, mode);
+            assert_eq!(x.is_infinite(), infinite, "{:?} {:?}", sign, mode);
+            assert!(infinite || x.abs().bit_eq(&d32::MAX));
+            assert_eq!(x.is_sign_minus(), sign);
+            assert!(flags.overflow && flags.inexact);
+        }
+        // rounding down instead stays finite without a flag
+        let (x, flags) = d32::finalize(false, 99999994, 89, RoundingMode::TiesToEven);
+        assert!(x.bit_eq(&d32::MAX));
+        assert!(!flags.overflow && flags.inexact);
+        // big coefficients at small exponents are fine, and
+        // small ones at big exponents get padded
+        let (x, flags) = d32::finalize(false, 12, QMAX + 5, RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (false, 1200000, QMAX));
+        assert_eq!(flags, Flags::default());
+    }
+
+    #[test]
+    fn finalize_underflow_before_rounding() {
+        // 9.9999995e-96 rounds up to the smallest normal, but
+        // was tiny beforehand
+        let (x, flags) = d32::finalize(false, 99999995, -103, RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (false, 1000000, -101));
+        assert!(x.is_normal());
+        assert!(flags.underflow && flags.inexact);
+        // exact subnormals are tiny but not inexact
+        let (x, flags) = d32::finalize(true, 5, -100, RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (true, 5, -100));
+        assert_eq!(flags, Flags::default());
+        // subnormals round at qmin, with ties to even
+        let x = d32::finalize(false, 15, -102, RoundingMode::TiesToEven).0;
+        assert_eq!(parts(x), (false, 2, QMIN));
+        let x = d32::finalize(false, 25, -102, RoundingMode::TiesToEven).0;
+        assert_eq!(parts(x), (false, 2, QMIN));
+        let x = d32::finalize(false, 25, -102, RoundingMode::TiesToAway).0;
+        assert_eq!(parts(x), (false, 3, QMIN));
+        // all the way down to zero, or up to the smallest
+        let (x, flags) = d32::finalize(true, 4, -102, RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (true, 0, QMIN));
+        assert!(flags.underflow && flags.inexact);
+        let x = d32::finalize(false, 1, -200, RoundingMode::TowardPositive).0;
+        assert_eq!(parts(x), (false, 1, QMIN));
+        let x = d32::finalize(true, 1, -200, RoundingMode::TowardPositive).0;
+        assert_eq!(parts(x), (true, 0, QMIN));
+        // zero is never tiny
+        let (x, flags) = d32::finalize(false, 0, -500, RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (false, 0, QMIN));
+        assert_eq!(flags, Flags::default());
+    }
+
+    fn parse(s: &str) -> d32 {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn from_str_keeps_exponent() {
+        assert_eq!(parts(parse("1.00")), (false, 100, -2));
+        assert_eq!(parts(parse("-0.0")), (true, 0, -1));
+        assert_eq!(parts(parse("1.5E+3")), (false, 15, 2));
+        assert_eq!(parts(parse("+.25e-1")), (false, 25, -3));
+        assert_eq!(parts(parse("7.")), (false, 7, 0));
+        assert_eq!(parts(parse("0001234")), (false, 1234, 0));
+    }
+
+    #[test]
+    fn from_str_rounds_and_saturates() {
+        assert_eq!(parts(parse("12345675")), (false, 1234568, 1));
+        assert_eq!(
+            parts(parse("1.2345665000000000000000000000000000000000001")),
+            (false, 1234567, -6)
+        );
+        assert!(parse("1E1000").is_infinite());
+        assert!(parse("-9.9999999E96").is_sign_minus());
+        assert!(parse("-9.9999999E96").is_infinite());
+        assert_eq!(parts(parse("1E-1000")), (false, 0, QMIN));
+        assert_eq!(parts(parse("0E99999999999999999999")), (false, 0, QMAX));
+    }
+
+    #[test]
+    fn from_str_specials() {
+        let x = parse("Inf");
+        assert!(x.is_infinite() && !x.is_sign_minus());
+        let x = parse("-Infinity");
+        assert!(x.is_infinite() && x.is_sign_minus());
+        let x = parse("NaN");
+        assert!(x.is_nan() && !x.is_signaling());
+        let x = parse("sNaN123");
+        assert!(x.is_signaling());
+        assert_eq!(x.nan_payload(), 123);
+        assert!(parse("-nan").is_sign_minus());
+    }
+
+    #[test]
+    fn from_str_errors() {
+        assert_eq!("".parse::<d32>().unwrap_err(), ParseDecimalError::Empty);
+        assert_eq!("-".parse::<d32>().unwrap_err(), ParseDecimalError::Empty);
+        assert_eq!(
+            ".".parse::<d32>().unwrap_err(),
+            ParseDecimalError::InvalidDigit
+        );
+        assert_eq!(
+            "1.2.3".parse::<d32>().unwrap_err(),
+            ParseDecimalError::InvalidDigit
+        );
+        assert_eq!(
+            "abc".parse::<d32>().unwrap_err(),
+            ParseDecimalError::InvalidDigit
+        );
+        assert_eq!(
+            " 1".parse::<d32>().unwrap_err(),
+            ParseDecimalError::InvalidDigit
+        );
+        assert_eq!(
+            "1e".parse::<d32>().unwrap_err(),
+            ParseDecimalError::InvalidExponent
+        );
+        assert_eq!(
+            "1e+-2".parse::<d32>().unwrap_err(),
+            ParseDecimalError::InvalidExponent
+        );
+        assert_eq!(
+            "nan1234567".parse::<d32>().unwrap_err(),
+            ParseDecimalError::PayloadTooLarge
+        );
+        assert_eq!(
+            "nanx".parse::<d32>().unwrap_err(),
+            ParseDecimalError::InvalidDigit
+        );
+    }
+
+    #[test]
+    fn parse_with_grouping() {
+        let us = ParseOptions {
+            thousands_separator: Some(','),
+            ..ParseOptions::default()
+        };
+        let parse_us = |s| d32::parse_with_options(s, us).map(parts);
+        assert_eq!(parse_us("1,234.56"), Ok((false, 123456, -2)));
+        assert_eq!(parse_us("-12,345,678"), Ok((true, 1234568, 1)));
+        assert_eq!(parse_us("999"), Ok((false, 999, 0)));
+        assert_eq!(parse_us("1234.5"), Ok((false, 12345, -1)));
+        assert_eq!(parse_us("1,000e3"), Ok((false, 1000, 3)));
+        assert_eq!(parse_us("inf").map(|_| ()), Ok(()));
+
+        let european = ParseOptions {
+            thousands_separator: Some('.'),
+            decimal_point: ',',
+        };
+        let x = d32::parse_with_options("1.234.567,8", european).unwrap();
+        assert_eq!(parts(x), (false, 1234568, 0));
+        let swiss = ParseOptions {
+            thousands_separator: Some('\''),
+            ..ParseOptions::default()
+        };
+        let x = d32::parse_with_options("1'000'000.25", swiss).unwrap();
+        assert_eq!(parts(x), (false, 1000000, 0));
+        let spaced = ParseOptions {
+            thousands_separator: Some('\u{a0}'),
+            decimal_point: '\u{b7}',
+        };
+        let x = d32::parse_with_options("12\u{a0}345\u{b7}6", spaced).unwrap();
+        assert_eq!(parts(x), (false, 123456, -1));
+    }
+
+    #[test]
+    fn parse_with_grouping_errors() {
+        let us = ParseOptions {
+            thousands_separator: Some(','),
+            ..ParseOptions::default()
+        };
+        for s in ["1,23", "1234,567", ",123", "1,,234", "1,234,", "12,34.5"] {
+            assert_eq!(
+                d32::parse_with_options(s, us),
+                Err(ParseDecimalError::InvalidGrouping),
+                "{}",
+                s
+            );
+        }
+        assert_eq!(
+            d32::parse_with_options("1.234,5", us),
+            Err(ParseDecimalError::InvalidDigit)
+        );
+        // the defaults are as strict as FromStr
+        assert_eq!(
+            d32::parse_with_options("1,234", ParseOptions::default()),
+            Err(ParseDecimalError::InvalidDigit)
+        );
+        assert_eq!(
+            d32::parse_with_options("1.5", ParseOptions::default()).map(parts),
+            Ok((false, 15, -1))
+        );
+        // a comma point doesn't accept a dot
+        let comma = ParseOptions {
+            decimal_point: ',',
+            ..ParseOptions::default()
+        };
+        assert_eq!(
+            d32::parse_with_options("1.5", comma),
+            Err(ParseDecimalError::InvalidDigit)
+        );
+        let same = ParseOptions {
+            thousands_separator: Some('.'),
+            decimal_point: '.',
+        };
+        assert!(d32::parse_with_options("1", same).is_err());
+        let digit = ParseOptions {
+            thousands_separator: Some('0'),
+            ..ParseOptions::default()
+        };
+        assert!(d32::parse_with_options("1", digit).is_err());
+    }
+
+    #[test]
+    fn to_parts() {
+        let parts = parse("-12.50").to_parts();
+        assert_eq!(
+            parts,
+            DecimalParts {
+                sign: true,
+                coefficient_digits: vec![1, 2, 5, 0],
+                exponent: -2,
+                special: None,
+            }
+        );
+        let parts = parse("0E-5").to_parts();
+        assert_eq!(parts.coefficient_digits, vec![0]);
+        assert_eq!(parts.exponent, -5);
+        let parts = d32::MAX.to_parts();
+        assert_eq!(parts.coefficient_digits, vec![9; 7]);
+        assert_eq!(parts.exponent, 90);
+        let parts = parse("-inf").to_parts();
+        assert!(parts.sign && parts.coefficient_digits.is_empty());
+        assert_eq!(parts.special, Some(Special::Infinity));
+        let parts = parse("NaN42").to_parts();
+        assert_eq!(parts.special, Some(Special::QuietNaN { payload: 42 }));
+        let parts = parse("-sNaN").to_parts();
+        assert!(parts.sign);
+        assert_eq!(parts.special, Some(Special::SignalingNaN { payload: 0 }));
+    }
+
+    #[test]
+    fn to_fixed_parts() {
+        let parts = parse("1.000001E-90").to_fixed_parts();
+        assert!(!parts.sign);
+        assert_eq!(parts.coefficient_digits(), &[1, 0, 0, 0, 0, 0, 1]);
+        assert_eq!(parts.exponent, -96);
+        assert_eq!(parts.special, None);
+        let parts = parse("7").to_fixed_parts();
+        assert_eq!(parts.coefficient_digits(), &[7]);
+        assert_eq!(parts.len, 1);
+        let parts = parse("sNaN999999").to_fixed_parts();
+        assert_eq!(
+            parts.special,
+            Some(Special::SignalingNaN { payload: 999999 })
+        );
+        assert!(parts.coefficient_digits().is_empty());
+        // noncanonical declets read as 999
+        let parts = d32(0x22500000 | 0x3ff).to_fixed_parts();
+        assert_eq!(parts.coefficient_digits(), &[9, 9, 9]);
+    }
+
+    #[test]
+    fn display_scientific_string() {
+        let cases = [
+            ((false, 123, 0), "123"),
+            ((true, 123, 0), "-123"),
+            ((false, 123, 1), "1.23E+3"),
+            ((false, 123, 3), "1.23E+5"),
+            ((false, 123, -1), "12.3"),
+            ((false, 123, -5), "0.00123"),
+            ((false, 123, -10), "1.23E-8"),
+            ((true, 123, -12), "-1.23E-10"),
+            ((false, 0, 0), "0"),
+            ((false, 0, -2), "0.00"),
+            ((false, 0, 2), "0E+2"),
+            ((true, 0, 0), "-0"),
+            ((false, 5, -6), "0.000005"),
+            ((false, 50, -7), "0.0000050"),
+            ((false, 5, -7), "5E-7"),
+        ];
+        for &((sign, coefficient, exponent), expected) in cases.iter() {
+            assert_eq!(dec(sign, coefficient, exponent).to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn display_specials() {
+        assert_eq!(d32::infinity(false).to_string(), "Infinity");
+        assert_eq!(d32::infinity(true).to_string(), "-Infinity");
+        assert_eq!(parse("NaN").to_string(), "NaN");
+        assert_eq!(parse("-sNaN").to_string(), "-sNaN");
+        assert_eq!(parse("NaN12").to_string(), "NaN12");
+        assert_eq!(parse("sNaN123").to_string(), "sNaN123");
+    }
+
+    #[test]
+    fn display_round_trips() {
+        for s in [
+            "1.00",
+            "-0",
+            "1.5E+3",
+            "0.000001",
+            "1E-7",
+            "9.999999E+96",
+            "1E-101",
+            "-Infinity",
+            "sNaN42",
+        ]
+        .iter()
+        {
+            assert_eq!(parse(s).to_string(), *s);
+            assert_eq!(parse(&parse(s).to_string()).0, parse(s).0);
+        }
+    }
+
+    #[test]
+    fn engineering_string() {
+        let cases = [
+            ("1.0E-7", "100E-9"),
+            ("1E-6", "0.000001"),
+            ("1.0E-6", "0.0000010"),
+            ("123E+1", "1.23E+3"),
+            ("1E+1", "10"),
+            ("1E+2", "100"),
+            ("1.23E+5", "123E+3"),
+            ("-1E-8", "-10E-9"),
+            ("5E-7", "500E-9"),
+            ("1E-101", "10E-102"),
+            ("1.234567E+96", "1.234567E+96"),
+            ("0E+1", "0.00E+3"),
+            ("0E+2", "0.0E+3"),
+            ("0E+3", "0E+3"),
+            ("0E-7", "0.0E-6"),
+            ("0E-8", "0.00E-6"),
+            ("0.00", "0.00"),
+            ("-Infinity", "-Infinity"),
+            ("sNaN7", "sNaN7"),
+        ];
+        for &(input, expected) in cases.iter() {
+            assert_eq!(parse(input).to_engineering_string(), expected);
+        }
+    }
+
+    #[test]
+    fn from_i32() {
+        assert_eq!(parts(d32::from(0i32)), (false, 0, 0));
+        assert_eq!(parts(d32::from(-42i32)), (true, 42, 0));
+        assert_eq!(parts(d32::from(1000000i32)), (false, 1000000, 0));
+        assert_eq!(parts(d32::from(9999999i32)), (false, 9999999, 0));
+        assert_eq!(parts(d32::from(-9999999i32)), (true, 9999999, 0));
+        // past 7 digits, ties go to even
+        assert_eq!(parts(d32::from(12345665i32)), (false, 1234566, 1));
+        assert_eq!(parts(d32::from(12345675i32)), (false, 1234568, 1));
+        assert_eq!(parts(d32::from(i32::MIN)), (true, 2147484, 3));
+        assert_eq!(parts(d32::from(i32::MAX)), (false, 2147484, 3));
+    }
+
+    #[test]
+    fn from_u32() {
+        assert_eq!(parts(d32::from(7u32)), (false, 7, 0));
+        assert_eq!(parts(d32::from(10000000u32)), (false, 1000000, 1));
+        assert_eq!(parts(d32::from(u32::MAX)), (false, 4294967, 3));
+    }
+
+    #[test]
+    fn from_digits_exact() {
+        let e = RoundingMode::TiesToEven;
+        let (x, flags) = d32::from_digits(false, &[1, 2, 3], -2, e);
+        assert_eq!(parts(x), (false, 123, -2));
+        assert_eq!(flags, Flags::default());
+        // leading zeros don't count against the precision
+        let (x, flags) = d32::from_digits(true, &[0, 0, 0, 1, 2, 3, 4, 5, 6, 7], 0, e);
+        assert_eq!(parts(x), (true, 1234567, 0));
+        assert_eq!(flags, Flags::default());
+        let (x, flags) = d32::from_digits(true, &[], 5, e);
+        assert_eq!(parts(x), (true, 0, 5));
+        assert_eq!(flags, Flags::default());
+        // trailing zeros past the precision fold into the exponent
+        let (x, flags) = d32::from_digits(false, &[1, 0, 0, 0, 0, 0, 0, 0, 0], 0, e);
+        assert_eq!(parts(x), (false, 1000000, 2));
+        assert_eq!(flags, Flags::default());
+    }
+
+    #[test]
+    fn from_digits_rounds() {
+        let digits = [1, 2, 3, 4, 5, 6, 7, 5];
+        let (x, flags) = d32::from_digits(false, &digits, 0, RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (false, 1234568, 1));
+        assert!(flags.inexact);
+        let x = d32::from_digits(false, &digits, 0, RoundingMode::TowardZero).0;
+        assert_eq!(parts(x), (false, 1234567, 1));
+        // a nonzero digit far down still counts
+        let mut long = [0u8; 60];
+        long[0] = 5;
+        long[59] = 1;
+        let x = d32::from_digits(true, &long, -59, RoundingMode::TowardNegative).0;
+        assert_eq!(parts(x), (true, 5000001, -6));
+        let (x, flags) = d32::from_digits(false, &[1], 97, RoundingMode::TiesToEven);
+        assert!(x.is_infinite() && flags.overflow);
+        let (x, flags) = d32::from_digits(false, &[1, 5], -103, RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (false, 0, QMIN));
+        assert!(flags.underflow && flags.inexact);
+        let (x, flags) = d32::from_digits(false, &[1, 10], 0, RoundingMode::TiesToEven);
+        assert!(x.is_nan() && flags.invalid);
+    }
+
+    #[test]
+    fn quantum_keeps_exponent() {
+        let x = dec(true, 1234, -2);
+        assert_eq!(parts(x.quantum()), (false, 1, -2));
+        assert_eq!(x.quantum_exponent(), Some(-2));
+        let x = dec(false, 0, 17);
+        assert_eq!(parts(x.quantum()), (false, 1, 17));
+        assert_eq!(x.quantum_exponent(), Some(17));
+        let x = dec(false, 1000000, QMAX);
+        assert_eq!(x.quantum_exponent(), Some(90));
+        assert_eq!(d32::INFINITY.quantum().to_bits(), d32::INFINITY.to_bits());
+        assert_eq!(d32::NEG_INFINITY.quantum_exponent(), None);
+        assert_eq!(d32::NAN.quantum_exponent(), None);
+    }
+
+    #[test]
+    fn quantum_of_subnormals() {
+        // the smallest subnormal is its own quantum
+        let smallest = dec(false, 1, QMIN);
+        assert!(smallest.is_subnormal());
+        assert_eq!(smallest.quantum().to_bits(), smallest.to_bits());
+        assert_eq!(smallest.quantum_exponent(), Some(-101));
+        let x = dec(true, 999999, QMIN);
+        assert!(x.is_subnormal());
+        assert_eq!(x.quantum().to_bits(), smallest.to_bits());
+        assert_eq!(x.quantum_exponent(), Some(-101));
+        // subnormals needn't sit at qmin
+        let x = dec(false, 5, -100);
+        assert!(x.is_subnormal());
+        assert_eq!(parts(x.quantum()), (false, 1, -100));
+        assert_eq!(x.quantum_exponent(), Some(-100));
+    }
+
+    #[test]
+    fn from_i64_rounds() {
+        assert_eq!(
+            parts(d32::from_i64(-1234567, RoundingMode::TowardZero)),
+            (true, 1234567, 0)
+        );
+        let big = 123456789012i64;
+        assert_eq!(
+            parts(d32::from_i64(big, RoundingMode::TiesToEven)),
+            (false, 1234568, 5)
+        );
+        assert_eq!(
+            parts(d32::from_i64(big, RoundingMode::TowardZero)),
+            (false, 1234567, 5)
+        );
+        assert_eq!(
+            parts(d32::from_i64(-big, RoundingMode::TowardNegative)),
+            (true, 1234568, 5)
+        );
+        assert_eq!(
+            parts(d32::from_i64(-big, RoundingMode::TowardPositive)),
+            (true, 1234567, 5)
+        );
+        // -9223372036854775808
+        assert_eq!(
+            parts(d32::from_i64(i64::MIN, RoundingMode::TiesToEven)),
+            (true, 9223372, 12)
+        );
+        assert_eq!(
+            parts(d32::from_i64(i64::MIN, RoundingMode::TowardNegative)),
+            (true, 9223373, 12)
+        );
+        assert_eq!(
+            parts(d32::from_i64(i64::MAX, RoundingMode::TowardZero)),
+            (false, 9223372, 12)
+        );
+    }
+
+    #[test]
+    fn from_u64_rounds() {
+        assert_eq!(
+            parts(d32::from_u64(0, RoundingMode::TiesToEven)),
+            (false, 0, 0)
+        );
+        // 18446744073709551615
+        assert_eq!(
+            parts(d32::from_u64(u64::MAX, RoundingMode::TiesToEven)),
+            (false, 1844674, 13)
+        );
+        assert_eq!(
+            parts(d32::from_u64(u64::MAX, RoundingMode::TowardPositive)),
+            (false, 1844675, 13)
+        );
+        assert_eq!(
+            parts(d32::from_u64(99999995, RoundingMode::TiesToAway)),
+            (false, 1000000, 2)
+        );
+    }
+
+    #[test]
+    fn to_i32_rounds_value() {
+        assert_eq!(parse("1.9E0").to_i32(RoundingMode::TiesToEven), Ok(2));
+        assert_eq!(parse("1.9").to_i32(RoundingMode::TowardZero), Ok(1));
+        assert_eq!(parse("-2.5").to_i32(RoundingMode::TiesToEven), Ok(-2));
+        assert_eq!(parse("-2.5").to_i32(RoundingMode::TiesToAway), Ok(-3));
+        assert_eq!(parse("-2.1").to_i32(RoundingMode::TowardNegative), Ok(-3));
+        assert_eq!(
+            parse("0.0000001").to_i32(RoundingMode::TowardPositive),
+            Ok(1)
+        );
+        assert_eq!(parse("1.5E-100").to_i32(RoundingMode::TiesToAway), Ok(0));
+        assert_eq!(parse("12E3").to_i32(RoundingMode::TiesToEven), Ok(12000));
+        assert_eq!(
+            parse("-2.147483E9").to_i32(RoundingMode::TiesToEven),
+            Ok(-2147483000)
+        );
+        assert_eq!(parse("-0").to_i32(RoundingMode::TiesToEven), Ok(0));
+    }
+
+    #[test]
+    fn to_i32_invalid() {
+        let invalid = Err(Flags {
+            invalid: true,
+            ..Flags::default()
+        });
+        assert_eq!(
+            parse("2.147484E9").to_i32(RoundingMode::TiesToEven),
+            invalid
+        );
+        assert_eq!(parse("1E90").to_i32(RoundingMode::TiesToEven), invalid);
+        assert_eq!(parse("Inf").to_i32(RoundingMode::TiesToEven), invalid);
+        assert_eq!(parse("NaN").to_i32(RoundingMode::TiesToEven), invalid);
+        assert_eq!(parse("0E90").to_i32(RoundingMode::TiesToEven), Ok(0));
+    }
+
+    #[test]
+    fn to_i64_exact() {
+        let inexact = Err(Flags {
+            inexact: true,
+            ..Flags::default()
+        });
+        let invalid = Err(Flags {
+            invalid: true,
+            ..Flags::default()
+        });
+        assert_eq!(parse("1.00E2").to_i64_exact(), Ok(100));
+        assert_eq!(parse("-7.000").to_i64_exact(), Ok(-7));
+        assert_eq!(parse("9.223372E18").to_i64_exact(), Ok(9223372000000000000));
+        assert_eq!(parse("-0.00").to_i64_exact(), Ok(0));
+        assert_eq!(parse("1.5").to_i64_exact(), inexact);
+        assert_eq!(parse("-0.001").to_i64_exact(), inexact);
+        assert_eq!(parse("9.223373E18").to_i64_exact(), invalid);
+        assert_eq!(parse("-Inf").to_i64_exact(), invalid);
+        assert_eq!(parse("sNaN").to_i64_exact(), invalid);
+    }
+
+    #[test]
+    fn from_f64_rounds_exact_binary_value() {
+        // 0.1 is really 0.1000000000000000055511151231257827...
+        assert_eq!(
+            parts(d32::from_f64(0.1, RoundingMode::TiesToEven)),
+            (false, 1000000, -7)
+        );
+        assert_eq!(
+            parts(d32::from_f64(0.1, RoundingMode::TowardPositive)),
+            (false, 1000001, -7)
+        );
+        assert_eq!(
+            parts(d32::from_f64(-0.1, RoundingMode::TowardNegative)),
+            (true, 1000001, -7)
+        );
+        // exact values keep their shortest coefficient
+        assert_eq!(
+            parts(d32::from_f64(0.5, RoundingMode::TiesToEven)),
+            (false, 5, -1)
+        );
+        assert_eq!(
+            parts(d32::from_f64(-1024.0, RoundingMode::TiesToEven)),
+            (true, 1024, 0)
+        );
+        assert_eq!(
+            parts(d32::from_f64(1e20, RoundingMode::TiesToEven)),
+            (false, 1000000, 14)
+        );
+        assert_eq!(
+            parts(d32::from_f64(1.0 / 3.0, RoundingMode::TiesToEven)),
+            (false, 3333333, -7)
+        );
+    }
+
+    #[test]
+    fn from_f64_specials() {
+        assert_eq!(
+            parts(d32::from_f64(-0.0, RoundingMode::TiesToEven)),
+            (true, 0, 0)
+        );
+        assert_eq!(
+            parts(d32::from_f64(0.0, RoundingMode::TiesToEven)),
+            (false, 0, 0)
+        );
+        let x = d32::from_f64(f64::NAN, RoundingMode::TiesToEven);
+        assert!(x.is_nan() && !x.is_signaling());
+        assert!(d32::from_f64(f64::NEG_INFINITY, RoundingMode::TiesToEven).is_sign_minus());
+        assert!(d32::from_f64(1e300, RoundingMode::TiesToEven).is_infinite());
+        assert_eq!(
+            parts(d32::from_f64(1e300, RoundingMode::TowardZero)),
+            (false, 9999999, QMAX)
+        );
+        assert_eq!(
+            parts(d32::from_f64(5e-324, RoundingMode::TiesToEven)),
+            (false, 0, QMIN)
+        );
+        assert_eq!(
+            parts(d32::from_f64(5e-324, RoundingMode::TowardPositive)),
+            (false, 1, QMIN)
+        );
+    }
+
+    #[test]
+    fn to_f64_nearest() {
+        assert_eq!(parse("0.1").to_f64(), 0.1);
+        assert_eq!(parse("1.234567E+96").to_f64(), 1.234567e96);
+        assert_eq!(parse("1E-101").to_f64(), 1e-101);
+        assert_eq!(parse("-2.50").to_f64(), -2.5);
+        let zero = parse("-0E+3").to_f64();
+        assert!(zero == 0.0 && zero.is_sign_negative());
+        assert!(parse("NaN").to_f64().is_nan());
+        assert!(parse("-sNaN").to_f64().is_sign_negative());
+        assert_eq!(parse("-Inf").to_f64(), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn from_f32() {
+        // 0.1f32 is really 0.100000001490116119384765625
+        assert_eq!(
+            parts(d32::from_f32(0.1, RoundingMode::TiesToEven)),
+            (false, 1000000, -7)
+        );
+        assert_eq!(
+            parts(d32::from_f32(0.1, RoundingMode::TowardPositive)),
+            (false, 1000001, -7)
+        );
+        assert_eq!(
+            parts(d32::from_f32(-0.0, RoundingMode::TiesToEven)),
+            (true, 0, 0)
+        );
+        // the smallest f32 subnormal, 1.4012984643...E-45
+        let x = d32::from_f32(f32::from_bits(1), RoundingMode::TiesToEven);
+        assert_eq!(parts(x), (false, 1401298, -51));
+        assert!(d32::from_f32(f32::NAN, RoundingMode::TiesToEven).is_nan());
+        assert!(d32::from_f32(f32::NEG_INFINITY, RoundingMode::TiesToEven).is_sign_minus());
+    }
+
+    #[test]
+    fn to_f32_nearest() {
+        assert_eq!(parse("0.1").to_f32(), 0.1f32);
+        assert_eq!(parse("3.402823E+38").to_f32(), 3.402823e38f32);
+        assert_eq!(parse("1E+39").to_f32(), f32::INFINITY);
+        assert_eq!(parse("1.401298E-45").to_f32(), f32::from_bits(1));
+        let zero = parse("-1E-50").to_f32();
+        assert!(zero == 0.0 && zero.is_sign_negative());
+        assert!(parse("-NaN").to_f32().is_nan());
+    }
+
+    #[test]
+    fn partial_eq_is_numeric() {
+        assert_eq!(parse("1.0"), parse("1.00"));
+        assert_eq!(parse("100"), parse("1E+2"));
+        assert_eq!(parse("0"), parse("-0E+5"));
+        assert_eq!(parse("-Inf"), parse("-Infinity"));
+        assert_eq!(parse("-2.5"), parse("-25E-1"));
+        assert_ne!(parse("1.0"), parse("1.01"));
+        assert_ne!(parse("1"), parse("-1"));
+        assert_ne!(parse("Inf"), parse("9.999999E96"));
+        assert_ne!(parse("1E-101"), parse("0"));
+        assert_ne!(parse("Inf"), parse("-Inf"));
+    }
+
+    #[test]
+    fn partial_eq_nans() {
+        let nan = parse("NaN");
+        assert_ne!(nan, nan);
+        assert_ne!(parse("sNaN"), parse("sNaN"));
+        assert_ne!(nan, parse("1"));
+        assert_ne!(parse("0"), nan);
+    }
+
+    #[test]
+    fn partial_ord_is_numeric() {
+        use std::cmp::Ordering;
+        assert_eq!(
+            parse("1.0").partial_cmp(&parse("1.00")),
+            Some(Ordering::Equal)
+        );
+        assert_eq!(
+            parse("-0").partial_cmp(&parse("0E-5")),
+            Some(Ordering::Equal)
+        );
+        assert!(parse("1.5") < parse("2"));
+        assert!(parse("-1.5") > parse("-2"));
+        assert!(parse("-1E-101") < parse("0"));
+        assert!(parse("1E-101") > parse("-0"));
+        assert!(parse("9.999999E96") < parse("Inf"));
+        assert!(parse("-Inf") < parse("-9.999999E96"));
+        assert!(parse("12345E-3") < parse("1.2346E1"));
+        assert!(parse("99E1") < parse("1E3"));
+        // coefficients padded to the same length
+        assert!(parse("1.000001") > parse("1"));
+        assert!(parse("1234567E90") > parse("1234566E90"));
+        assert!(parse("1E90") > parse("9999999E-101"));
+    }
+
+    #[test]
+    fn partial_ord_nans_unordered() {
+        let nan = parse("NaN");
+        let one = parse("1");
+        assert_eq!(nan.partial_cmp(&one), None);
+        assert_eq!(one.partial_cmp(&parse("-sNaN")), None);
+        assert!(nan.lt(&one) == nan.ge(&one));
+        assert_eq!(nan.partial_cmp(&nan), None);
+    }
+
+    #[test]
+    fn total_ord_sorts() {
+        let expected = [
+            "-NaN",
+            "-sNaN",
+            "-Infinity",
+            "-1.0E+3",
+            "-1.00E+3",
+            "-2.5",
+            "-2.50",
+            "-1E-101",
+            "-0E+2",
+            "-0",
+            "-0.00",
+            "0.00",
+            "0",
+            "0E+2",
+            "1E-101",
+            "1.000",
+            "1.0",
+            "1",
+            "10",
+            "1E+1",
+            "9.999999E+96",
+            "Infinity",
+            "sNaN",
+            "NaN",
+        ];
+        let mut values: Vec<TotalOrd> = expected.iter().rev().map(|s| TotalOrd(parse(s))).collect();
+        values.swap(3, 17);
+        values.swap(8, 20);
+        values.sort();
+        let sorted: Vec<String> = values.iter().map(|x| x.0.to_string()).collect();
+        assert_eq!(sorted, expected);
+    }
+
+    #[test]
+    fn total_ord_in_btree_map() {
+        use std::collections::BTreeMap;
+        let mut map = BTreeMap::new();
+        map.insert(TotalOrd(parse("1.0")), "one point oh");
+        map.insert(TotalOrd(parse("1.00")), "one point oh oh");
+        map.insert(TotalOrd(parse("-0")), "negative zero");
+        map.insert(TotalOrd(parse("0")), "zero");
+        assert_eq!(map.len(), 4);
+        assert_eq!(map[&TotalOrd(parse("1.00"))], "one point oh oh");
+        assert_eq!(map.keys().next().unwrap().0.to_string(), "-0");
+    }
+
+    #[test]
+    fn is_integer() {
+        for s in [
+            "0", "-0", "0E-50", "1", "-7", "1.00E2", "100", "5.000", "1E90", "9999999", "-1.20E1",
+        ] {
+            assert!(parse(s).is_integer(), "{}", s);
+        }
+        for s in [
+            "1.5",
+            "0.1",
+            "-1.000001E1",
+            "1E-101",
+            "inf",
+            "-inf",
+            "NaN",
+            "sNaN",
+        ] {
+            assert!(!parse(s).is_integer(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn is_subnormal_uses_adjusted_exponent() {
+        for s in [
+            "1E-95",
+            "1000000E-101",
+            "9999999E-101",
+            "10E-96",
+            "1.0E-95",
+            "1",
+            "9.999999E96",
+        ]
+        .iter()
+        {
+            assert!(!parse(s).is_subnormal(), "{}", s);
+            assert!(parse(s).is_normal(), "{}", s);
+            assert!(matches!(parse(s).class(), Class::PositiveNormal), "{}", s);
+        }
+        for s in ["1E-96", "999999E-101", "1E-101", "99E-97", "-9.99999E-96"].iter() {
+            assert!(parse(s).is_subnormal(), "{}", s);
+            assert!(!parse(s).is_normal(), "{}", s);
+        }
+        assert!(matches!(parse("-1E-96").class(), Class::NegativeSubnormal));
+        assert!(!parse("0E-101").is_subnormal());
+        assert!(!parse("Inf").is_subnormal());
+        assert!(!parse("NaN").is_subnormal());
+    }
+
+    #[test]
+    fn total_order_far_apart_exponents() {
+        // biased exponents 5 and 190, far past anything
+        // 10u64.pow could scale between
+        let pairs = [
+            ("1E-96", "1E+89"),
+            ("9999999E-101", "1E+90"),
+            ("1234567E-80", "2E-50"),
+            ("1E-101", "9.999999E+96"),
+            ("-1E+89", "-1E-96"),
+            ("-9.999999E+96", "-1E-101"),
+        ];
+        for &(a, b) in pairs.iter() {
+            let (a, b) = (parse(a), parse(b));
+            assert!(a.total_order(&b));
+            assert!(!b.total_order(&a));
+            assert!(a.abs().total_order_mag(&b.abs()) == (a.abs() < b.abs()));
+        }
+    }
+
+    #[test]
+    fn total_order_sequence() {
+        // strictly ascending, so every pair is ordered by
+        // position alone
+        let ascending: Vec<d32> = "-NaN7 -NaN2 -sNaN7 -sNaN2 -Inf -9.999999E+96 -1E+3 -1.0E+3 \
+            -1000 -1.5 -1E-101 -0E+3 -0 -0E-101 0E-101 0 0E+3 1E-101 0.000001 1.000 1 1.5 \
+            1000 1E+3 9.999999E+96 Inf sNaN2 sNaN7 NaN2 NaN7"
+            .split_whitespace()
+            .map(parse)
+            .collect();
+        for (i, a) in ascending.iter().enumerate() {
+            for (j, b) in ascending.iter().enumerate() {
+                assert_eq!(a.total_order(b), i <= j, "{} {}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn total_order_nan_payloads() {
+        let (small, big) = (parse("NaN5"), parse("NaN123456"));
+        assert!(small.total_order(&big));
+        assert!(!big.total_order(&small));
+        assert!(small.total_order(&small));
+        let (small, big) = (parse("-NaN5"), parse("-NaN123456"));
+        assert!(big.total_order(&small));
+        assert!(!small.total_order(&big));
+        let (small, big) = (parse("sNaN7"), parse("sNaN70"));
+        assert!(small.total_order(&big));
+        assert!(!big.total_order(&small));
+        // signaling before quiet, whatever the payloads
+        assert!(parse("sNaN999999").total_order(&parse("NaN1")));
+        assert!(!parse("NaN1").total_order(&parse("sNaN999999")));
+        assert!(parse("-NaN1").total_order(&parse("-sNaN999999")));
+        // and the sign before all of that
+        assert!(parse("-NaN1").total_order(&parse("NaN0")));
+        assert!(!parse("NaN0").total_order(&parse("-NaN1")));
+    }
+
+    #[test]
+    fn nan_payloads() {
+        let nan = d32::nan(false, 123456);
+        assert!(nan.is_nan() && !nan.is_signaling());
+        assert!(nan.is_canonical());
+        assert_eq!(nan.payload(), Some(123456));
+        assert_eq!(nan.to_string(), "NaN123456");
+        let snan = d32::signaling_nan(true, 42);
+        assert!(snan.is_signaling() && snan.is_sign_minus());
+        assert!(snan.is_canonical());
+        assert_eq!(snan.payload(), Some(42));
+        assert_eq!(snan.to_string(), "-sNaN42");
+        // too many digits keeps just the last six
+        let big = d32::nan(false, 1234567890);
+        assert!(big.is_canonical());
+        assert_eq!(big.payload(), Some(567890));
+        assert_eq!(d32::signaling_nan(false, u32::MAX).payload(), Some(967295));
+        assert_eq!(parse("NaN999").payload(), Some(999));
+        assert_eq!(parse("sNaN").payload(), Some(0));
+        assert_eq!(parse("1").payload(), None);
+        assert_eq!(parse("-Inf").payload(), None);
+        assert_eq!(parse("0").payload(), None);
+    }
+
+    #[test]
+    fn scaleb_moves_exponent() {
+        let even = RoundingMode::TiesToEven;
+        assert_eq!(parts(parse("1.234").scaleb(2, even)), (false, 1234, -1));
+        assert_eq!(parts(parse("-1.234").scaleb(-5, even)), (true, 1234, -8));
+        assert_eq!(parts(parse("-0E+3").scaleb(-10, even)), (true, 0, -7));
+        assert_eq!(
+            parts(parse("1E+80").scaleb(i32::MIN, even)),
+            (false, 0, QMIN)
+        );
+        // padding zeros in is fine so long as the digits fit
+        assert_eq!(parts(parse("12").scaleb(95, even)), (false, 1200000, 90));
+        assert!(parse("inf").scaleb(-3, even).is_infinite());
+        assert!(parse("-inf").scaleb(3, even).is_sign_minus());
+    }
+
+    #[test]
+    fn scaleb_out_of_range() {
+        let even = RoundingMode::TiesToEven;
+        // into the subnormals, losing digits as it goes
+        let (x, flags) = parse("1234567").scaleb_with_flags(-106, even);
+        assert_eq!(parts(x), (false, 12, QMIN));
+        assert!(flags.underflow && flags.inexact);
+        let (x, flags) = parse("1234500").scaleb_with_flags(-103, even);
+        assert_eq!(parts(x), (false, 12345, QMIN));
+        assert_eq!(flags, Flags::default());
+        let (x, flags) = parse("-5").scaleb_with_flags(-102, RoundingMode::TowardZero);
+        assert_eq!(parts(x), (true, 0, QMIN));
+        assert!(flags.underflow && flags.inexact);
+        // and past emax
+        let (x, flags) = parse("1.5").scaleb_with_flags(96, even);
+        assert_eq!(parts(x), (false, 1500000, 90));
+        assert_eq!(flags, Flags::default());
+        let (x, flags) = parse("1.5").scaleb_with_flags(97, even);
+        assert!(x.is_infinite() && flags.overflow);
+        let (x, flags) = parse("-1").scaleb_with_flags(i32::MAX, RoundingMode::TowardZero);
+        assert_eq!(parts(x), (true, MAX_COEFFICIENT, QMAX));
+        assert!(flags.overflow);
+    }
+
+    #[test]
+    fn scaleb_nans() {
+        let (x, flags) = parse("sNaN12").scaleb_with_flags(1, RoundingMode::TiesToEven);
+        assert!(x.is_nan() && !x.is_signaling());
+        assert_eq!(x.payload(), Some(12));
+        assert!(flags.invalid);
+        let (x, flags) = parse("-NaN3").scaleb_with_flags(1, RoundingMode::TiesToEven);
+        assert_eq!(x.to_string(), "-NaN3");
+        assert!(!flags.invalid);
+    }
+
+    #[test]
+    fn logb_adjusted_exponent() {
+        assert_eq!(parts(parse("9.999E2").logb()), (false, 2, 0));
+        assert_eq!(parts(parse("1000").logb()), (false, 3, 0));
+        assert_eq!(parts(parse("-0.05").logb()), (true, 2, 0));
+        assert_eq!(parts(parse("1").logb()), (false, 0, 0));
+        assert_eq!(parts(parse("9.999999E+96").logb()), (false, 96, 0));
+        assert_eq!(parts(parse("1E-101").logb()), (true, 101, 0));
+        // scaleb undoes it, up to cohort
+        let x = parse("-4.5E-17");
+        let n = x.logb().to_i32(RoundingMode::TiesToEven).unwrap();
+        assert_eq!(x.scaleb(-n, RoundingMode::TiesToEven), parse("-4.5"));
+    }
+
+    #[test]
+    fn logb_specials() {
+        let (x, flags) = parse("-0E+5").logb_with_flags();
+        assert!(x.is_infinite() && x.is_sign_minus());
+        assert!(flags.div_by_zero);
+        let (x, flags) = parse("-inf").logb_with_flags();
+        assert!(x.is_infinite() && !x.is_sign_minus());
+        assert_eq!(flags, Flags::default());
+        let (x, flags) = parse("sNaN8").logb_with_flags();
+        assert_eq!(x.to_string(), "NaN8");
+        assert!(flags.invalid);
+    }
+
+    #[test]
+    fn quantize_rounds_to_exponent() {
+        let even = RoundingMode::TiesToEven;
+        let (x, flags) = parse("2.4567").quantize_with_flags(&parse("0.01"), even);
+        assert_eq!(parts(x), (false, 246, -2));
+        assert!(flags.inexact);
+        assert_eq!(x.to_string(), "2.46");
+        let (x, flags) = parse("2.4").quantize_with_flags(&parse("-7E-3"), even);
+        assert_eq!(parts(x), (false, 2400, -3));
+        assert_eq!(flags, Flags::default());
+        assert_eq!(
+            parts(parse("-0.125").quantize(&parse("1E-2"), even)),
+            (true, 12, -2)
+        );
+        assert_eq!(
+            parts(parse("-0.125").quantize(&parse("1E-2"), RoundingMode::TiesToAway)),
+            (true, 13, -2)
+        );
+        assert_eq!(
+            parts(parse("0.0001").quantize(&parse("1"), RoundingMode::TowardPositive)),
+            (false, 1, 0)
+        );
+        assert_eq!(
+            parts(parse("-0.4").quantize(&parse("1"), even)),
+            (true, 0, 0)
+        );
+        assert_eq!(
+            parts(parse("0E-50").quantize(&parse("1E+90"), even)),
+            (false, 0, 90)
+        );
+        assert_eq!(
+            parts(parse("0E+90").quantize(&parse("1E-101"), even)),
+            (false, 0, -101)
+        );
+        assert_eq!(
+            parts(parse("1E-101").quantize(&parse("1E+90"), even)),
+            (false, 0, 90)
+        );
+    }
+
+    #[test]
+    fn quantize_invalid() {
+        let even = RoundingMode::TiesToEven;
+        // too many digits, either by padding or by carrying
+        assert!(
+            parse("1")
+                .quantize_with_flags(&parse("1E-7"), even)
+                .1
+                .invalid
+        );
+        assert!(
+            !parse("1")
+                .quantize_with_flags(&parse("1E-6"), even)
+                .1
+                .invalid
+        );
+        assert!(
+            parse("9999999.5")
+                .quantize_with_flags(&parse("1"), even)
+                .1
+                .invalid
+        );
+        assert!(parse("1E+90").quantize(&parse("1E-101"), even).is_nan());
+        // infinities only go with each other
+        let (x, flags) = parse("-inf").quantize_with_flags(&parse("inf"), even);
+        assert!(x.is_infinite() && x.is_sign_minus());
+        assert_eq!(flags, Flags::default());
+        assert!(
+            parse("inf")
+                .quantize_with_flags(&parse("1"), even)
+                .1
+                .invalid
+        );
+        assert!(
+            parse("1")
+                .quantize_with_flags(&parse("-inf"), even)
+                .1
+                .invalid
+        );
+        let (x, flags) = parse("1").quantize_with_flags(&parse("sNaN4"), even);
+        assert_eq!(x.to_string(), "NaN4");
+        assert!(flags.invalid);
+    }
+
+    #[test]
+    fn round_to_integral_exact() {
+        let even = RoundingMode::TiesToEven;
+        let (x, flags) = parse("1.5").round_to_integral_exact_with_flags(even);
+        assert_eq!(parts(x), (false, 2, 0));
+        assert!(flags.inexact);
+        let (x, flags) = parse("2.00").round_to_integral_exact_with_flags(even);
+        assert_eq!(parts(x), (false, 2, 0));
+        assert_eq!(flags, Flags::default());
+        // already integral exponents stay put
+        let (x, flags) = parse("12E+3").round_to_integral_exact_with_flags(even);
+        assert_eq!(parts(x), (false, 12, 3));
+        assert_eq!(flags, Flags::default());
+        let modes = [
+            (RoundingMode::TiesToEven, [2, 2, 3, -2, -2]),
+            (RoundingMode::TiesToAway, [3, 2, 3, -3, -2]),
+            (RoundingMode::TowardZero, [2, 2, 2, -2, -2]),
+            (RoundingMode::TowardPositive, [3, 3, 3, -2, -2]),
+            (RoundingMode::TowardNegative, [2, 2, 2, -3, -3]),
+        ];
+        for &(mode, expected) in modes.iter() {
+            for (s, &e) in ["2.5", "2.1", "2.9", "-2.5", "-2.1"]
+                .iter()
+                .zip(expected.iter())
+            {
+                let x = parse(s).round_to_integral_exact(mode);
+                assert_eq!(x, d32::from(e), "{} in {:?}", s, mode);
+            }
+        }
+        let (x, flags) = parse("-0.4").round_to_integral_exact_with_flags(even);
+        assert_eq!(parts(x), (true, 0, 0));
+        assert!(flags.inexact);
+        assert_eq!(
+            parts(parse("0E-50").round_to_integral_exact(even)),
+            (false, 0, 0)
+        );
+        assert_eq!(
+            parts(parse("1E-101").round_to_integral_exact(even)),
+            (false, 0, 0)
+        );
+        let (x, flags) = parse("-inf").round_to_integral_exact_with_flags(even);
+        assert!(x.is_infinite() && x.is_sign_minus());
+        assert_eq!(flags, Flags::default());
+        let (x, flags) = parse("sNaN").round_to_integral_exact_with_flags(even);
+        assert!(x.is_nan() && !x.is_signaling() && flags.invalid);
+    }
+
+    #[test]
+    fn round_to_digits_significant_figures() {
+        let e = RoundingMode::TiesToEven;
+        let cases = [
+            ("3.141593", 3, "3.14"),
+            ("-2.718282", 1, "-3"),
+            ("123456.7", 2, "1.2E+5"),
+            ("0.0012345", 3, "0.00123"),
+            ("0.0012355", 4, "0.001236"),
+            ("0.0012345", 4, "0.001234"),
+            ("9.996", 3, "10.0"),
+            ("99.95", 3, "100"),
+            // few enough digits already, padding zeros and all
+            ("1.50", 3, "1.50"),
+            ("42", 7, "42"),
+            ("-0E+5", 1, "-0E+5"),
+            ("-Inf", 2, "-Infinity"),
+            ("1234567", 7, "1234567"),
+        ];
+        for &(x, n, expected) in cases.iter() {
+            assert_eq!(
+                parse(x).round_to_digits(n, e).to_string(),
+                expected,
+                "{} to {}",
+                x,
+                n
+            );
+        }
+        let up = RoundingMode::TowardPositive;
+        assert_eq!(parse("1.001").round_to_digits(2, up).to_string(), "1.1");
+        assert_eq!(parse("-1.009").round_to_digits(2, up).to_string(), "-1.0");
+        // dropping zeros is exact
+        let (x, flags) = parse("2.50").round_to_digits_with_flags(2, e);
+        assert_eq!(x.to_string(), "2.5");
+        assert_eq!(flags, Flags::default());
+        assert!(parse("2.51").round_to_digits_with_flags(2, e).1.inexact);
+        // carrying out of the top can overflow
+        let (x, flags) = d32::MAX.round_to_digits_with_flags(3, e);
+        assert!(x.is_infinite() && flags.overflow && flags.inexact);
+        // and up there the exponent can't rise past qmax, so
+        // the value has three digits but the coefficient doesn't
+        let x = d32::MAX.round_to_digits(3, RoundingMode::TowardZero);
+        assert_eq!(x.to_string(), "9.990000E+96");
+        // subnormals round too, and underflow when inexact
+        let (x, flags) = parse("1.23456E-96").round_to_digits_with_flags(2, e);
+        assert_eq!(x.to_string(), "1.2E-96");
+        assert!(flags.underflow && flags.inexact);
+        let (x, flags) = parse("sNaN7").round_to_digits_with_flags(3, e);
+        assert_eq!(x.to_string(), "NaN7");
+        assert!(flags.invalid);
+    }
+
+    #[test]
+    #[should_panic]
+    fn round_to_digits_needs_a_digit() {
+        d32::ONE.round_to_digits(0, RoundingMode::TiesToEven);
+    }
+
+    #[test]
+    fn round_to_integral_quiet() {
+        let modes = [
+            RoundingMode::TiesToEven,
+            RoundingMode::TiesToAway,
+            RoundingMode::TowardZero,
+            RoundingMode::TowardPositive,
+            RoundingMode::TowardNegative,
+        ];
+        let values = [
+            "1.5", "-2.5", "2.00", "0.001", "-0.999", "12E+3", "-0E-9", "9999.999",
+        ];
+        for &mode in modes.iter() {
+            for s in values.iter() {
+                let (quiet, flags) = parse(s).round_to_integral_with_flags(mode);
+                let exact = parse(s).round_to_integral_exact(mode);
+                assert_eq!(quiet.0, exact.0, "{} in {:?}", s, mode);
+                assert_eq!(flags, Flags::default());
+            }
+        }
+        assert_eq!(
+            parts(parse("12.5E-1").round_to_integral(RoundingMode::TiesToEven)),
+            (false, 1, 0)
+        );
+        let (x, flags) = parse("-sNaN5").round_to_integral_with_flags(RoundingMode::TiesToEven);
+        assert_eq!(x.to_string(), "-NaN5");
+        assert!(flags.invalid);
+    }
+
+    #[test]
+    fn next_up_and_down() {
+        let steps = [
+            ("0", "1E-101"),
+            ("-0", "1E-101"),
+            ("-1E-101", "-0E-101"),
+            ("1E-101", "2E-101"),
+            ("1", "1.000001"),
+            ("-1", "-0.9999999"),
+            ("9.999999", "10.00000"),
+            ("9.99999E-96", "1.000000E-95"),
+            ("-1.000000E-95", "-9.99999E-96"),
+            ("1.234E+20", "1.234001E+20"),
+            ("-9.999999E+96", "-9.999998E+96"),
+            ("9.999998E+96", "9.999999E+96"),
+            ("-inf", "-9.999999E+96"),
+        ];
+        for &(a, b) in steps.iter() {
+            let (a, b) = (parse(a), parse(b));
+            assert_eq!(a.next_up(), b, "{} up", a);
+            assert!(a < b || a.is_zero() && b.is_zero() || a.is_infinite());
+            if !a.is_zero() {
+                assert_eq!(b.next_down(), a, "{} down", b);
+            }
+        }
+        assert!(parse("9.999999E+96").next_up().is_infinite());
+        assert!(parse("inf").next_up().is_infinite());
+        assert!(parse("-9.999999E+96").next_down().is_sign_minus());
+        assert!(parse("-9.999999E+96").next_down().is_infinite());
+        assert_eq!(parse("0").next_down(), parse("-1E-101"));
+        assert_eq!(parse("-sNaN3").next_up().to_string(), "-NaN3");
+        assert_eq!(parse("NaN3").next_down().to_string(), "NaN3");
+    }
+
+    #[test]
+    fn ulp_is_grid_spacing() {
+        let cases = [
+            ("1", "0.000001"),
+            ("1.000", "0.000001"),
+            ("-9.999999", "0.000001"),
+            ("10", "0.00001"),
+            ("1234567E+5", "1E+5"),
+            ("9.999999E+96", "1E+90"),
+            ("1E-95", "1E-101"),
+            ("1.000000E-95", "1E-101"),
+            ("9.99999E-96", "1E-101"),
+            ("-1E-101", "1E-101"),
+            ("0E+20", "1E-101"),
+            ("-0", "1E-101"),
+            ("-inf", "Infinity"),
+        ];
+        for &(x, ulp) in cases.iter() {
+            assert_eq!(parse(x).ulp().to_string(), ulp, "{}", x);
+        }
+        // quantum follows the exponent as stored instead
+        assert_eq!(parse("1.000").quantum().to_string(), "0.001");
+        assert_eq!(parse("-sNaN5").ulp().to_string(), "-NaN5");
+        // and the ulp is the step next_up takes from a
+        // positive value
+        for x in ["1.000", "42", "9.99999E-96", "3.141593E+50"] {
+            let x = parse(x);
+            assert_eq!(x.next_up() - x, x.ulp(), "{}", x);
+        }
+    }
+
+    #[test]
+    fn next_toward_steps_once() {
+        let one = parse("1");
+        assert_eq!(one.next_toward(&parse("2")).to_string(), "1.000001");
+        assert_eq!(one.next_toward(&parse("inf")).to_string(), "1.000001");
+        assert_eq!(one.next_toward(&parse("-5")).to_string(), "0.9999999");
+        assert_eq!(
+            parse("0").next_toward(&parse("-0.5")).to_string(),
+            "-1E-101"
+        );
+        assert!(parse("9.999999E+96")
+            .next_toward(&parse("inf"))
+            .is_infinite());
+        assert_eq!(
+            parse("-inf").next_toward(&d32::ZERO).to_string(),
+            "-9.999999E+96"
+        );
+        // numerically equal leaves self alone, exponent and
+        // sign and all
+        let x = parse("1.000").next_toward(&parse("1E+0"));
+        assert_eq!(x.to_string(), "1.000");
+        assert!(parse("-0").next_toward(&parse("0E+5")).is_sign_minus());
+        assert_eq!(parse("inf").next_toward(&parse("inf")), parse("inf"));
+        // nans propagate quietly, first operand first
+        assert_eq!(parse("NaN4").next_toward(&one).to_string(), "NaN4");
+        assert_eq!(one.next_toward(&parse("-sNaN2")).to_string(), "-NaN2");
+        assert_eq!(
+            parse("NaN4").next_toward(&parse("sNaN2")).to_string(),
+            "NaN2"
+        );
+    }
+
+    #[test]
+    fn minimum_and_maximum() {
+        let (a, b) = (parse("-3"), parse("2.5"));
+        assert_eq!(a.minimum(&b), a);
+        assert_eq!(b.minimum(&a), a);
+        assert_eq!(a.maximum(&b), b);
+        assert_eq!(b.maximum(&a), b);
+        // signed zeros, either way round
+        let (neg, pos) = (parse("-0"), parse("0"));
+        assert!(neg.minimum(&pos).is_sign_minus());
+        assert!(pos.minimum(&neg).is_sign_minus());
+        assert!(!neg.maximum(&pos).is_sign_minus());
+        assert!(!pos.maximum(&neg).is_sign_minus());
+        // within a cohort, the smaller exponent is the minimum
+        let (a, b) = (parse("1.0"), parse("1"));
+        assert_eq!(parts(a.minimum(&b)), (false, 10, -1));
+        assert_eq!(parts(b.maximum(&a)), (false, 1, 0));
+        assert!(parse("-inf").minimum(&parse("-9E+96")).is_infinite());
+        assert!(parse("inf").maximum(&parse("9E+96")).is_infinite());
+    }
+
+    #[test]
+    fn minimum_and_maximum_nans() {
+        let (x, flags) = parse("1").minimum_with_flags(&parse("NaN7"));
+        assert_eq!(x.to_string(), "NaN7");
+        assert_eq!(flags, Flags::default());
+        let (x, flags) = parse("-NaN2").maximum_with_flags(&parse("inf"));
+        assert_eq!(x.to_string(), "-NaN2");
+        assert_eq!(flags, Flags::default());
+        let (x, flags) = parse("NaN1").maximum_with_flags(&parse("sNaN2"));
+        assert_eq!(x.to_string(), "NaN2");
+        assert!(flags.invalid);
+        let (x, flags) = parse("sNaN").minimum_with_flags(&parse("0"));
+        assert!(x.is_nan() && !x.is_signaling() && flags.invalid);
+    }
+
+    #[test]
+    fn minimum_and_maximum_number() {
+        let (x, flags) = parse("NaN7").minimum_number_with_flags(&parse("-2"));
+        assert_eq!(x, parse("-2"));
+        assert_eq!(flags, Flags::default());
+        let (x, flags) = parse("3").maximum_number_with_flags(&parse("-sNaN"));
+        assert_eq!(x, parse("3"));
+        assert!(flags.invalid);
+        let (x, flags) = parse("NaN1").minimum_number_with_flags(&parse("sNaN2"));
+        assert_eq!(x.to_string(), "NaN2");
+        assert!(flags.invalid);
+        let (x, flags) = parse("NaN1").maximum_number_with_flags(&parse("NaN2"));
+        assert_eq!(x.to_string(), "NaN1");
+        assert_eq!(flags, Flags::default());
+        // otherwise exactly minimum and maximum
+        assert!(parse("0").minimum_number(&parse("-0")).is_sign_minus());
+        assert!(!parse("-0").maximum_number(&parse("0")).is_sign_minus());
+        assert_eq!(parse("-3").minimum_number(&parse("2")), parse("-3"));
+        assert_eq!(parse("-3").maximum_number(&parse("2")), parse("2"));
+    }
+
+    #[test]
+    fn magnitude_minimum_and_maximum() {
+        let (a, b) = (parse("-3"), parse("2"));
+        assert_eq!(a.minimum_magnitude(&b), b);
+        assert_eq!(b.minimum_magnitude(&a), b);
+        assert_eq!(a.maximum_magnitude(&b), a);
+        assert_eq!(b.maximum_magnitude(&a), a);
+        // equal magnitudes fall back on the sign
+        let (a, b) = (parse("-2"), parse("2.0"));
+        assert_eq!(a.minimum_magnitude(&b), a);
+        assert_eq!(a.maximum_magnitude(&b), b);
+        assert!(parse("0").minimum_magnitude(&parse("-0")).is_sign_minus());
+        assert!(!parse("-0").maximum_magnitude(&parse("0")).is_sign_minus());
+        assert!(parse("-inf")
+            .maximum_magnitude(&parse("9E+90"))
+            .is_infinite());
+        // nans as in minimum and maximum
+        let (x, flags) = parse("NaN4").minimum_magnitude_with_flags(&parse("1"));
+        assert_eq!(x.to_string(), "NaN4");
+        assert_eq!(flags, Flags::default());
+        let (x, flags) = parse("1").maximum_magnitude_with_flags(&parse("sNaN"));
+        assert!(x.is_nan() && flags.invalid);
+        let (x, flags) = parse("sNaN").minimum_magnitude_number_with_flags(&parse("-5"));
+        assert_eq!(x, parse("-5"));
+        assert!(flags.invalid);
+        assert_eq!(
+            parse("-5").maximum_magnitude_number(&parse("NaN")),
+            parse("-5")
+        );
+        assert!(parse("NaN")
+            .maximum_magnitude_number(&parse("NaN"))
+            .is_nan());
+    }
+
+    #[test]
+    fn remainder_rounds_quotient_to_even() {
+        let cases = [
+            ("10", "3", "1"),
+            ("11", "3", "-1"),
+            ("-11", "3", "1"),
+            ("5", "2", "1"),
+            ("7", "2", "-1"),
+            ("-7", "2", "1"),
+            ("6", "4", "-2"),
+            ("2", "4", "2"),
+            ("10", "0.3", "0.1"),
+            ("3.6", "1.3", "-0.3"),
+            ("1", "1E-100", "0E-100"),
+            ("1.234567", "1E+20", "1.234567"),
+            ("7E+5", "0.3", "0.1"),
+            ("9.999999E+96", "3", "0"),
+        ];
+        for &(x, y, r) in cases.iter() {
+            let (x, y, r) = (parse(x), parse(y), parse(r));
+            let (got, flags) = x.remainder_with_flags(&y);
+            assert_eq!(got, r, "{} rem {}", x, y);
+            assert_eq!(got.is_sign_minus(), r.is_sign_minus(), "{} rem {}", x, y);
+            assert_eq!(flags, Flags::default());
+        }
+        // zero results keep x's sign and the smaller exponent
+        assert_eq!(parts(parse("-6").remainder(&parse("0.3"))), (true, 0, -1));
+        assert_eq!(parts(parse("0E+5").remainder(&parse("7"))), (false, 0, 0));
+    }
+
+    #[test]
+    fn remainder_specials() {
+        let (x, flags) = parse("inf").remainder_with_flags(&parse("2"));
+        assert!(x.is_nan() && flags.invalid);
+        let (x, flags) = parse("1").remainder_with_flags(&parse("-0"));
+        assert!(x.is_nan() && flags.invalid);
+        let (x, flags) = parse("-1.5").remainder_with_flags(&parse("inf"));
+        assert_eq!(parts(x), (true, 15, -1));
+        assert_eq!(flags, Flags::default());
+        let (x, flags) = parse("1").remainder_with_flags(&parse("sNaN9"));
+        assert_eq!(x.to_string(), "NaN9");
+        assert!(flags.invalid);
+    }
+
+    #[test]
+    fn divide_integer_truncates() {
+        let cases = [
+            ("2", "3", "0"),
+            ("10", "3", "3"),
+            ("1", "0.3", "3"),
+            ("10.0", "3", "3"),
+            ("-7", "2", "-3"),
+            ("7", "-2", "-3"),
+            ("-0", "5", "-0"),
+            ("1E3", "1E-3", "1000000"),
+            ("9999999", "1", "9999999"),
+            ("1E-30", "5", "0"),
+            ("1E+20", "1E+14", "1000000"),
+        ];
+        for (x, y, q) in cases {
+            let (got, flags) = parse(x).divide_integer_with_flags(&parse(y));
+            assert_eq!(got.to_string(), q, "{} / {}", x, y);
+            assert_eq!(flags, Flags::default());
+        }
+        for (x, y) in [
+            ("1E7", "1"),
+            ("1", "1E-7"),
+            ("9E90", "1E-90"),
+            ("0", "0"),
+            ("inf", "-inf"),
+        ] {
+            let (got, flags) = parse(x).divide_integer_with_flags(&parse(y));
+            assert!(got.is_nan() && flags.invalid, "{} / {}", x, y);
+        }
+        let (x, flags) = parse("-1").divide_integer_with_flags(&parse("0"));
+        assert!(x.is_infinite() && x.is_sign_minus() && flags.div_by_zero);
+        assert_eq!(
+            parse("inf").divide_integer(&parse("-2")).to_string(),
+            "-Infinity"
+        );
+        assert_eq!(parse("2").divide_integer(&parse("-inf")).to_string(), "-0");
+        assert!(parse("0E9").divide_integer(&parse("1E-50")).is_zero());
+    }
+
+    #[test]
+    fn remainder_gda_takes_sign_of_dividend() {
+        let cases = [
+            ("10", "3", "1"),
+            ("10.0", "3", "1.0"),
+            ("1", "0.3", "0.1"),
+            ("3.6", "1.3", "1.0"),
+            ("-10", "3", "-1"),
+            ("10", "-3", "1"),
+            ("-7", "2", "-1"),
+            ("-6", "2", "-0"),
+            ("1E-30", "5", "1E-30"),
+            ("5", "1E-6", "0.000000"),
+        ];
+        for (x, y, r) in cases {
+            let (got, flags) = parse(x).remainder_gda_with_flags(&parse(y));
+            assert_eq!(got.to_string(), r, "{} rem {}", x, y);
+            assert_eq!(flags, Flags::default());
+        }
+        // where remainder rounds the quotient to nearest instead
+        assert_eq!(parse("-7").remainder(&parse("2")).to_string(), "1");
+        let (x, flags) = parse("1E7").remainder_gda_with_flags(&parse("1"));
+        assert!(x.is_nan() && flags.invalid);
+        let (x, flags) = parse("1").remainder_gda_with_flags(&parse("0"));
+        assert!(x.is_nan() && flags.invalid);
+        let (x, flags) = parse("inf").remainder_gda_with_flags(&parse("1"));
+        assert!(x.is_nan() && flags.invalid);
+        assert_eq!(
+            parse("-1.5").remainder_gda(&parse("inf")).to_string(),
+            "-1.5"
+        );
+    }
+
+    #[test]
+    fn compare_signaling() {
+        let (one, two, also_one) = (parse("1"), parse("2"), parse("1.000"));
+        assert_eq!(
+            one.compare_signaling_equal(&also_one),
+            (true, Flags::default())
+        );
+        assert_eq!(one.compare_signaling_equal(&two), (false, Flags::default()));
+        assert_eq!(one.compare_signaling_less(&two), (true, Flags::default()));
+        assert_eq!(two.compare_signaling_less(&one), (false, Flags::default()));
+        assert_eq!(
+            one.compare_signaling_less_equal(&also_one),
+            (true, Flags::default())
+        );
+        assert_eq!(
+            two.compare_signaling_greater(&one),
+            (true, Flags::default())
+        );
+        assert_eq!(
+            one.compare_signaling_greater_equal(&two),
+            (false, Flags::default())
+        );
+        assert_eq!(
+            parse("-0").compare_signaling_greater_equal(&parse("0")),
+            (true, Flags::default())
+        );
+        // every nan is invalid, and nothing holds
+        let invalid = Flags {
+            invalid: true,
+            ..Flags::default()
+        };
+        for nan in ["NaN", "-NaN5", "sNaN"].iter() {
+            let nan = parse(nan);
+            assert_eq!(nan.compare_signaling_equal(&nan), (false, invalid));
+            assert_eq!(one.compare_signaling_greater(&nan), (false, invalid));
+            assert_eq!(nan.compare_signaling_greater_equal(&one), (false, invalid));
+            assert_eq!(one.compare_signaling_less(&nan), (false, invalid));
+            assert_eq!(nan.compare_signaling_less_equal(&one), (false, invalid));
+        }
+    }
+
+    #[test]
+    fn compare_names_all_four() {
+        assert_eq!(parse("1").compare(&parse("2")), Comparison::Less);
+        assert_eq!(parse("1.0").compare(&parse("1")), Comparison::Equal);
+        assert_eq!(parse("-0").compare(&parse("0E+5")), Comparison::Equal);
+        assert_eq!(parse("inf").compare(&parse("9E+96")), Comparison::Greater);
+        assert_eq!(parse("-inf").compare(&parse("-inf")), Comparison::Equal);
+        assert_eq!(parse("NaN").compare(&parse("NaN")), Comparison::Unordered);
+        assert_eq!(parse("1").compare(&parse("-sNaN")), Comparison::Unordered);
+    }
+
+    #[test]
+    fn canonicalize_reencodes() {
+        // 0x3ff and 0x16e are the long ways to write 999 and 888
+        let x = d32(0x22500000 | 0x3ff << 10 | 0x16e);
+        let y = x.canonicalize();
+        assert_eq!(y.0, 0x22500000 | 0x0ff << 10 | 0x06e);
+        assert_eq!(parts(y), (false, 999888, 0));
+        assert!(y.is_canonical());
+        // junk in the unused bits of specials
+        let nan = d32(0xfc000000 | 0x01f00000 | 0x005);
+        let nan = nan.canonicalize();
+        assert_eq!(nan.0, 0xfc000005);
+        assert!(nan.is_canonical());
+        let snan = d32(0x7e100000 | 0x3ff).canonicalize();
+        assert_eq!(snan.0, 0x7e0000ff);
+        assert!(snan.is_canonical());
+        let inf = d32(0xf9234567).canonicalize();
+        assert_eq!(inf.0, 0xf8000000);
+        assert!(inf.is_canonical());
+        // already canonical values come back as they were
+        for s in [
+            "1.234567E-50",
+            "-9.999999E+96",
+            "-0E+3",
+            "NaN12",
+            "-sNaN",
+            "inf",
+        ]
+        .iter()
+        {
+            let x = parse(s);
+            assert_eq!(x.canonicalize().0, x.0, "{}", s);
+        }
+    }
+
+    #[test]
+    fn is_zero_decodes_noncanonical() {
+        // noncanonical spellings of 999, 888 and 898
+        for &declet in [0x1ff, 0x2ff, 0x3ff, 0x16e, 0x26e, 0x37e].iter() {
+            let x = d32(0x22500000 | declet);
+            assert!(!x.is_zero(), "{:#x}", declet);
+            assert!(x.is_normal(), "{:#x}", declet);
+            let high = d32(0xa2500000 | declet << 10);
+            assert!(!high.is_zero(), "{:#x}", declet);
+            assert_eq!(
+                high.compare(&x.negate().scaleb(3, RoundingMode::TiesToEven)),
+                Comparison::Equal
+            );
+        }
+        assert!(d32(0x22500000).is_zero());
+        assert!(d32(0x80000000).is_zero());
+        assert!(!parse("inf").is_zero());
+        assert!(!parse("NaN").is_zero());
+    }
+
+    #[test]
+    fn unbiased_exponent() {
+        assert_eq!(parse("1.23").unbiased_exponent(), Some(-2));
+        assert_eq!(parse("-5E+90").unbiased_exponent(), Some(90));
+        assert_eq!(parse("1E-101").unbiased_exponent(), Some(-101));
+        assert_eq!(parse("-0").unbiased_exponent(), Some(0));
+        assert_eq!(parse("9.999999E+96").unbiased_exponent(), Some(90));
+        assert_eq!(parse("inf").unbiased_exponent(), None);
+        assert_eq!(parse("NaN").unbiased_exponent(), None);
+        assert_eq!(parse("sNaN").unbiased_exponent(), None);
+    }
+
+    #[test]
+    fn coefficient() {
+        assert_eq!(parse("1.23").coefficient(), Some(123));
+        assert_eq!(parse("-9.999999E+96").coefficient(), Some(9999999));
+        assert_eq!(parse("8.000001").coefficient(), Some(8000001));
+        assert_eq!(parse("0").coefficient(), Some(0));
+        assert_eq!(parse("-0E-7").coefficient(), Some(0));
+        assert_eq!(parse("-inf").coefficient(), None);
+        assert_eq!(parse("NaN123").coefficient(), None);
+        // which is enough to put any finite value back together
+        for s in ["-1.234567E-50", "7E+90", "-0E+3", "905.0"].iter() {
+            let x = parse(s);
+            let y = d32::from_parts(
+                x.is_sign_minus(),
+                x.coefficient().unwrap(),
+                x.unbiased_exponent().unwrap(),
+            );
+            assert_eq!(x.0, y.0, "{}", s);
+        }
+    }
+
+    #[test]
+    fn digits() {
+        assert_eq!(d32::from_parts(false, 1050000, 0).digits(), 7);
+        assert_eq!(parse("42").digits(), 2);
+        assert_eq!(parse("-4.2E-50").digits(), 2);
+        assert_eq!(parse("1.000").digits(), 4);
+        assert_eq!(parse("0").digits(), 1);
+        assert_eq!(parse("-0E+9").digits(), 1);
+        assert_eq!(parse("1E-101").digits(), 1);
+        assert_eq!(parse("9.999999E+96").digits(), 7);
+        assert_eq!(parse("inf").digits(), 1);
+        assert_eq!(parse("NaN12345").digits(), 5);
+        assert_eq!(parse("sNaN").digits(), 1);
+    }
+
+    #[test]
+    fn is_754_version() {
+        const VERSIONS: [bool; 3] = [
+            d32::is_754_version_1985(),
+            d32::is_754_version_2008(),
+            d32::is_754_version_2019(),
+        ];
+        assert_eq!(VERSIONS, [false, true, true]);
+    }
+
+    #[test]
+    fn constants() {
+        assert_eq!(d32::ZERO.0, dec(false, 0, 0).0);
+        assert_eq!(d32::NEG_ZERO.0, dec(true, 0, 0).0);
+        assert_eq!(d32::ONE.0, dec(false, 1, 0).0);
+        assert_eq!(d32::MAX.0, dec(false, MAX_COEFFICIENT, QMAX).0);
+        assert_eq!(d32::MIN_POSITIVE.0, dec(false, 1, EMIN).0);
+        assert_eq!(d32::INFINITY.0, parse("inf").0);
+        assert_eq!(d32::NEG_INFINITY.0, parse("-inf").0);
+        assert_eq!(d32::NAN.0, parse("NaN").0);
+        assert!(d32::MIN_POSITIVE.is_normal());
+        assert_eq!(d32::MIN_POSITIVE_SUBNORMAL.0, dec(false, 1, QMIN).0);
+        assert!(d32::MIN_POSITIVE.next_down().is_subnormal());
+        assert!(d32::MAX.next_up().is_infinite());
+        assert_eq!(
+            d32::ONE.add(&d32::ONE, RoundingMode::TiesToEven),
+            d32::from(2)
+        );
+    }
+
+    #[test]
+    fn subnormal_boundary_walk() {
+        // every step up from the smallest subnormal is another
+        // 1E-101, all 999999 of them subnormal until the
+        // smallest normal; past it, every value is normal
+        let mut x = d32::MIN_POSITIVE_SUBNORMAL;
+        assert_eq!(x.to_string(), "1E-101");
+        assert!(d32::MIN_POSITIVE_SUBNORMAL.next_down().is_zero());
+        let mut steps = 0;
+        while x.is_subnormal() {
+            assert!(!x.is_normal() && !x.is_zero());
+            assert_eq!(x.class(), Class::PositiveSubnormal);
+            assert_eq!(x.negate().class(), Class::NegativeSubnormal);
+            x = x.next_up();
+            steps += 1;
+        }
+        assert_eq!(steps, 999999);
+        assert_eq!(x, d32::MIN_POSITIVE);
+        assert_eq!(x.to_string(), "1.000000E-95");
+        for _ in 0..1000 {
+            assert!(x.is_normal() && !x.is_subnormal());
+            assert!(x.negate().is_normal() && !x.negate().is_subnormal());
+            x = x.next_up();
+        }
+        // the same boundary whatever the cohort member
+        assert!(parse("1E-95").is_normal());
+        assert!(parse("10E-96").is_normal());
+        assert!(parse("1000000E-101").is_normal());
+        assert!(parse("9.99999E-96").is_subnormal());
+        assert!(parse("999999E-101").is_subnormal());
+        // with a seventh digit it would be below qmin, and
+        // rounds up across the boundary
+        assert!(parse("9.999999E-96").is_normal());
+    }
+
+    #[test]
+    fn default_is_positive_zero() {
+        let x = d32::default();
+        assert_eq!(x.0, d32::ZERO.0);
+        assert!(x.is_zero() && !x.is_sign_minus());
+        assert_eq!(x.unbiased_exponent(), Some(0));
+        #[derive(Default)]
+        struct Account {
+            balance: d32,
+        }
+        assert_eq!(Account::default().balance.0, d32::ZERO.0);
+    }
+
+    #[test]
+    fn bits_round_trip() {
+        assert_eq!(d32::ONE.to_bits(), 0x22500001);
+        assert_eq!(d32::from_bits(0x22500001), d32::ONE);
+        assert_eq!(parse("-7.50").to_bits(), dec(true, 750, -2).0);
+        // noncanonical encodings come through untouched
+        for &bits in [0x225003ff, 0x7e1fffff, 0xf9234567, 0x00000000].iter() {
+            assert_eq!(d32::from_bits(bits).to_bits(), bits);
+        }
+        assert!(!d32::from_bits(0xf9234567).is_canonical());
+        const TEN: d32 = d32::from_bits(0x22500010);
+        assert_eq!(TEN, d32::from(10));
+    }
+
+    #[test]
+    fn hash_agrees_with_eq() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::collections::HashMap;
+        use std::hash::{Hash, Hasher};
+        let hash = |x: d32| {
+            let mut h = DefaultHasher::new();
+            x.hash(&mut h);
+            h.finish()
+        };
+        // d32 itself can't be Eq, what with nans, but a key
+        // type that rules them out can lean on its Hash
+        #[derive(PartialEq, Hash)]
+        struct Key(d32);
+        impl Eq for Key {}
+        let mut map = HashMap::new();
+        map.insert(Key(parse("1.0")), "one");
+        assert_eq!(map.get(&Key(parse("1.00"))), Some(&"one"));
+        assert_eq!(map.get(&Key(parse("1"))), Some(&"one"));
+        assert_eq!(map.get(&Key(parse("-1"))), None);
+        let same = [
+            ("0", "-0E+5"),
+            ("1.5E+3", "1500"),
+            ("-2.000000E-90", "-2E-90"),
+            ("inf", "inf"),
+        ];
+        for &(a, b) in same.iter() {
+            let (a, b) = (parse(a), parse(b));
+            assert_eq!(a, b);
+            assert_eq!(hash(a), hash(b), "{} {}", a, b);
+        }
+        // noncanonical encodings hash by value too
+        let noncanonical = d32(0x22500000 | 0x3ff);
+        assert_eq!(hash(noncanonical), hash(parse("999")));
+        assert_eq!(hash(parse("NaN5")), hash(parse("-sNaN")));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_string_and_bits() {
+        let x = parse("-1.250E-7");
+        let json = serde_json::to_string(&x).unwrap();
+        assert_eq!(json, "\"-1.250E-7\"");
+        let y: d32 = serde_json::from_str(&json).unwrap();
+        assert_eq!(x.0, y.0);
+        let nan: d32 = serde_json::from_str("\"-sNaN42\"").unwrap();
+        assert_eq!(nan.to_string(), "-sNaN42");
+        assert!(serde_json::from_str::<d32>("\"1.2.3\"").is_err());
+        assert!(serde_json::from_str::<d32>("1.5").is_err());
+
+        #[derive(serde::Serialize, serde::Deserialize)]
+        struct Raw {
+            #[serde(with = "crate::serde_bits")]
+            value: d32,
+        }
+        // bits keep even noncanonical encodings exactly
+        let raw = Raw {
+            value: d32::from_bits(0x225003ff),
+        };
+        let json = serde_json::to_string(&raw).unwrap();
+        assert_eq!(json, format!("{{\"value\":{}}}", 0x225003ffu32));
+        let back: Raw = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.value.to_bits(), 0x225003ff);
+    }
+
+    #[test]
+    fn operators() {
+        let even = RoundingMode::TiesToEven;
+        let (a, b, c) = (parse("1.5"), parse("2"), parse("3.25"));
+        assert_eq!((a + b * c).0, a.add(&b.multiply(&c, even), even).0);
+        assert_eq!((a - b).0, a.subtract(&b, even).0);
+        assert_eq!((a / b).0, parse("0.75").0);
+        assert_eq!((-a).0, parse("-1.5").0);
+        assert_eq!((-parse("NaN3")).to_string(), "-NaN3");
+        assert_eq!(parse("1") / parse("3"), parse("0.3333333"));
+        assert_eq!(parse("2") / parse("3"), parse("0.6666667"));
+        assert!((d32::ONE / d32::ZERO).is_infinite());
+        let mut x = d32::ONE;
+        x += b;
+        assert_eq!(x, parse("3"));
+        x -= a;
+        assert_eq!(x, parse("1.5"));
+        x *= c;
+        assert_eq!(x.0, parse("4.875").0);
+        x /= parse("0.5");
+        assert_eq!(x, parse("9.75"));
+    }
+
+    #[test]
+    fn context_accumulates_flags() {
+        let mut ctx = Context::new(RoundingMode::TowardZero);
+        let third = ctx.div(&d32::ONE, &parse("3"));
+        assert_eq!(third.0, parse("0.3333333").0);
+        assert!(ctx.flags.inexact);
+        // an exact operation after that doesn't clear anything
+        let sum = ctx.add(&parse("1"), &parse("2"));
+        assert_eq!(sum, parse("3"));
+        assert!(ctx.flags.inexact);
+        assert!(!ctx.flags.div_by_zero);
+        let big = ctx.mul(&d32::MAX, &parse("10"));
+        assert_eq!(big.0, d32::MAX.0);
+        assert!(ctx.flags.overflow);
+        assert!(ctx.sqrt(&parse("-1")).is_nan());
+        assert!(ctx.flags.invalid);
+        ctx.flags = Flags::default();
+        assert_eq!(ctx.fma(&parse("2"), &parse("3"), &parse("4")), parse("10"));
+        assert_eq!(ctx.sub(&parse("2"), &parse("3")), parse("-1"));
+        assert_eq!(ctx.flags, Flags::default());
+        ctx.rounding = RoundingMode::TowardPositive;
+        assert_eq!(ctx.div(&d32::ONE, &parse("3")).0, parse("0.3333334").0);
+        assert!(ctx.flags.inexact);
+    }
+
+    #[test]
+    fn bit_eq_is_exact() {
+        assert!(parse("1.0").bit_eq(&dec(false, 10, -1)));
+        assert!(parse("1.0") == parse("1.00"));
+        assert!(!parse("1.0").bit_eq(&parse("1.00")));
+        assert!(!d32::ZERO.bit_eq(&d32::NEG_ZERO));
+        let nan = parse("NaN12");
+        assert!(nan.bit_eq(&nan));
+        assert!(!nan.bit_eq(&parse("NaN13")));
+        assert!(!nan.bit_eq(&parse("sNaN12")));
+        let noncanonical = d32(0x22500000 | 0x3ff);
+        assert!(noncanonical == noncanonical.canonicalize());
+        assert!(!noncanonical.bit_eq(&noncanonical.canonicalize()));
+        assert!(d32::MAX.bit_eq(&d32::from_bits(0x77f3fcff)));
+    }
+
+    #[test]
+    fn same_quantum_noncanonical() {
+        // 0x3ff is a noncanonical 999, 0x0ff the canonical one
+        let canonical = d32(0x22500000 | 0x0ff << 10 | 0x0ff);
+        let noncanonical = d32(0x22500000 | 0x3ff << 10 | 0x2ff);
+        assert!(canonical.same_quantum(&noncanonical));
+        assert!(noncanonical.same_quantum(&parse("5")));
+        assert!(noncanonical.same_quantum(&noncanonical.canonicalize()));
+        assert!(!noncanonical.same_quantum(&parse("5.0")));
+        // second form, lead digit 9, at qmin and qmax
+        let low = d32(0x64000000 | 0x3ff);
+        assert!(low.same_quantum(&parse("1E-101")));
+        let high = d32(0x77f00000 | 0x37e << 10);
+        assert!(high.same_quantum(&d32::MAX));
+        assert!(!high.same_quantum(&parse("1E+89")));
+        // specials only care about kind
+        assert!(parse("NaN1").same_quantum(&d32(0x7c1003ff)));
+        assert!(parse("-inf").same_quantum(&d32(0x79000001)));
+        assert!(!parse("inf").same_quantum(&parse("NaN")));
+        assert!(!parse("inf").same_quantum(&parse("9E+90")));
+    }
+
+    #[test]
+    fn category() {
+        use std::num::FpCategory;
+        let cases = [
+            ("NaN", FpCategory::Nan),
+            ("-sNaN4", FpCategory::Nan),
+            ("inf", FpCategory::Infinite),
+            ("-inf", FpCategory::Infinite),
+            ("1.5", FpCategory::Normal),
+            ("-9.999999E+96", FpCategory::Normal),
+            ("1E-95", FpCategory::Normal),
+            ("9.99999E-96", FpCategory::Subnormal),
+            ("-1E-101", FpCategory::Subnormal),
+            ("0", FpCategory::Zero),
+            ("-0E+50", FpCategory::Zero),
+        ];
+        for &(s, category) in cases.iter() {
+            assert_eq!(parse(s).category(), category, "{}", s);
+        }
+    }
+
+    #[test]
+    fn is_canonical_checks_declets() {
+        let noncanonical: Vec<u16> = (0..1024u16)
+            .filter(|&d| encode_declet(decode_declet(d)) != d)
+            .collect();
+        assert_eq!(noncanonical.len(), 24);
+        // the ones with the two bits above the indicator set
+        for &d in noncanonical.iter() {
+            assert_eq!(d & 0x06e, 0x06e, "{:#x}", d);
+            assert!(decode_declet(d) >= 888, "{:#x}", d);
+            // in both combination forms, in either declet
+            for &form in [0x22500000u32, 0x6e500000].iter() {
+                let x = d32(form | (d as u32) << 10);
+                assert!(!x.is_canonical(), "{:#x}", x.0);
+                assert!(x.canonicalize().is_canonical());
+                let y = d32(form | d as u32);
+                assert!(!y.is_canonical(), "{:#x}", y.0);
+            }
+            let nan = d32(0x7c000000 | d as u32);
+            assert!(!nan.is_canonical(), "{:#x}", nan.0);
+            assert!(nan.canonicalize().is_canonical());
+        }
+        for s in [
+            "0",
+            "-1.234567E-50",
+            "9.999999E+96",
+            "8.888888",
+            "NaN998899",
+            "-inf",
+        ]
+        .iter()
+        {
+            assert!(parse(s).is_canonical(), "{}", s);
+        }
+        let nine = d32(0x22500000 | 0x0ff << 10 | 0x0ff);
+        assert!(nine.is_canonical());
+        assert!(!d32(0x22500000 | 0x3ff << 10 | 0x0ff).is_canonical());
+    }
+
+    #[test]
+    fn rescale_to_exponent() {
+        let even = RoundingMode::TiesToEven;
+        let (x, flags) = parse("2.4567").rescale_with_flags(-2, even);
+        assert_eq!(parts(x), (false, 246, -2));
+        assert!(flags.inexact);
+        let (x, flags) = parse("-2.4").rescale_with_flags(-6, even);
+        assert_eq!(parts(x), (true, 2400000, -6));
+        assert_eq!(flags, Flags::default());
+        assert_eq!(
+            parts(parse("12345").rescale(3, RoundingMode::TowardZero)),
+            (false, 12, 3)
+        );
+        assert_eq!(parts(parse("0").rescale(QMAX, even)), (false, 0, QMAX));
+        // same as quantizing to anything with that exponent
+        for &(s, e) in [("1.5", 0), ("-9999999", 2), ("0.000123", -4), ("7E+20", 15)].iter() {
+            let x = parse(s);
+            let q = x.quantize_with_flags(&d32::from_parts(false, 1, e), even);
+            let r = x.rescale_with_flags(e, even);
+            assert_eq!((q.0).0, (r.0).0, "{} {}", s, e);
+            assert_eq!(q.1, r.1, "{} {}", s, e);
+        }
+        // too many digits, or an exponent out of range
+        assert!(parse("1").rescale_with_flags(-7, even).1.invalid);
+        assert!(parse("1").rescale_with_flags(QMIN - 1, even).1.invalid);
+        assert!(parse("0").rescale_with_flags(QMAX + 1, even).1.invalid);
+        assert!(parse("1").rescale_with_flags(i32::MAX, even).1.invalid);
+        assert!(parse("inf").rescale_with_flags(0, even).1.invalid);
+        let (x, flags) = parse("sNaN6").rescale_with_flags(0, even);
+        assert_eq!(x.to_string(), "NaN6");
+        assert!(flags.invalid);
+    }
+
+    #[test]
+    fn copy_sign_nans() {
+        let (pos, neg) = (parse("1"), parse("-1"));
+        let snan = parse("sNaN123");
+        let x = snan.copy_sign(&neg);
+        assert!(x.is_signaling() && x.is_sign_minus());
+        assert_eq!(x.payload(), Some(123));
+        assert_eq!(x.copy_sign(&pos).0, snan.0);
+        assert_eq!(parse("-NaN45").copy_sign(&pos).to_string(), "NaN45");
+        // only y's sign bit matters, nan or not
+        assert_eq!(parse("2.50").copy_sign(&parse("-NaN9")).0, parse("-2.50").0);
+        assert_eq!(parse("-0E+3").copy_sign(&parse("sNaN")).0, parse("0E+3").0);
+        assert_eq!(parse("inf").copy_sign(&parse("-sNaN")).0, parse("-inf").0);
+        // noncanonical bits are left alone too
+        let junk = d32(0x7e1003ff);
+        assert_eq!(junk.copy_sign(&neg).0, 0xfe1003ff);
+    }
+
+    #[test]
+    fn d64_known_encodings() {
+        // from the decimal64 test vectors: 1 and 9.999999999999999E+384
+        let one = d64::from_d32(d32::ONE);
+        assert_eq!(one.to_bits(), 0x2238000000000001);
+        let max = d64::from_bits(0x77fcff3fcff3fcff);
+        assert_eq!(max.coefficient(), Some(9999999999999999));
+        assert_eq!(max.unbiased_exponent(), Some(369));
+        assert!(max.is_normal());
+        let tiny = d64::from_bits(0x0000000000000001);
+        assert_eq!(tiny.unbiased_exponent(), Some(-398));
+        assert!(tiny.is_subnormal());
+        assert!(d64::from_bits(0x8000000000000000).is_zero());
+        assert!(d64::from_bits(0xf800000000000000).is_infinite());
+        assert!(d64::from_bits(0x7e00000000000000).is_signaling());
+    }
+
+    #[test]
+    fn d64_widens_exactly() {
+        for s in [
+            "0",
+            "-0E+90",
+            "1",
+            "-1.234567",
+            "9.999999E+96",
+            "-1E-101",
+            "8.888888E-50",
+            "1234567E+3",
+            "9E-95",
+        ]
+        .iter()
+        {
+            let x = parse(s);
+            let wide = d64::from_d32(x);
+            assert_eq!(wide.is_sign_minus(), x.is_sign_minus(), "{}", s);
+            assert_eq!(
+                wide.coefficient(),
+                x.coefficient().map(|c| c as u64),
+                "{}",
+                s
+            );
+            assert_eq!(wide.unbiased_exponent(), x.unbiased_exponent(), "{}", s);
+            assert_eq!(wide.digits(), x.digits(), "{}", s);
+            // d32's subnormals are all normal out here
+            assert!(wide.is_normal() || wide.is_zero(), "{}", s);
+            let (back, flags) = d32::from_d64_with_flags(wide, RoundingMode::TiesToEven);
+            assert_eq!(back.0, x.0, "{}", s);
+            assert_eq!(flags, Flags::default());
+        }
+        let nan = d64::from_d32(parse("-sNaN123456"));
+        assert!(nan.is_signaling() && nan.is_sign_minus());
+        assert_eq!(nan.payload(), Some(123456));
+        assert!(d64::from_d32(parse("-inf")).is_infinite());
+        assert!(d64::from_d32(parse("-inf")).is_sign_minus());
+    }
+
+    #[test]
+    fn d64_narrows_with_rounding() {
+        let even = RoundingMode::TiesToEven;
+        // 1234567.85 and friends, built from d32 pieces
+        let wide = d64::encode(false, 123456785, -2);
+        let (x, flags) = d32::from_d64_with_flags(wide, even);
+        assert_eq!(parts(x), (false, 1234568, 0));
+        assert!(flags.inexact);
+        let x = d32::from_d64(wide, RoundingMode::TowardZero);
+        assert_eq!(parts(x), (false, 1234567, 0));
+        let (x, flags) = d32::from_d64_with_flags(d64::encode(true, 1, 200), even);
+        assert!(x.is_infinite() && x.is_sign_minus() && flags.overflow);
+        let (x, flags) = d32::from_d64_with_flags(d64::encode(false, 15, -102), even);
+        assert_eq!(parts(x), (false, 2, QMIN));
+        assert!(flags.underflow);
+        let (x, flags) = d32::from_d64_with_flags(
+            d64::from_bits(0x7e00000000000000 | 0x3ff << 20 | 0x005),
+            even,
+        );
+        assert!(x.is_nan() && !x.is_signaling() && flags.invalid);
+        assert_eq!(x.payload(), Some(5));
+    }
+
+    #[test]
+    fn hex_string() {
+        assert_eq!(parse("-12.34").to_hex_string(), "-0x4d2p-2");
+        assert_eq!(parse("1.00").to_hex_string(), "0x64p-2");
+        assert_eq!(parse("1").to_hex_string(), "0x1p+0");
+        assert_eq!(parse("-0E+5").to_hex_string(), "-0x0p+5");
+        assert_eq!(parse("9.999999E+96").to_hex_string(), "0x98967fp+90");
+        assert_eq!(parse("inf").to_hex_string(), "inf");
+        assert_eq!(parse("-sNaN255").to_hex_string(), "-sNaN0xff");
+        assert_eq!(parse("NaN").to_hex_string(), "NaN0x0");
+        for s in [
+            "0",
+            "-0E-101",
+            "1.00",
+            "1.0",
+            "-1.234567E-50",
+            "9.999999E+96",
+            "1E-101",
+            "-inf",
+            "NaN999999",
+            "-sNaN7",
+            "sNaN",
+        ]
+        .iter()
+        {
+            let x = parse(s);
+            let y = d32::from_hex_string(&x.to_hex_string()).unwrap();
+            assert_eq!(x.0, y.0, "{}", s);
+        }
+    }
+
+    #[test]
+    fn from_hex_string() {
+        let even = RoundingMode::TiesToEven;
+        assert_eq!(
+            parts(d32::from_hex_string("0xFFp3").unwrap()),
+            (false, 255, 3)
+        );
+        assert_eq!(
+            parts(d32::from_hex_string("+0x10").unwrap()),
+            (false, 16, 0)
+        );
+        // too many digits rounds, like parsing decimal
+        let x = d32::from_hex_string("0x3b9aca07p0").unwrap();
+        assert_eq!(x.0, d32::from_parts(false, 1000000007, 0).0);
+        assert_eq!(x.0, d32::from_i64(1000000007, even).0);
+        assert!(d32::from_hex_string("0x1p+97").unwrap().is_infinite());
+        assert!(d32::from_hex_string("-Infinity").unwrap().is_sign_minus());
+        let errors = [
+            ("", ParseDecimalError::Empty),
+            ("-", ParseDecimalError::Empty),
+            ("12", ParseDecimalError::InvalidDigit),
+            ("0x", ParseDecimalError::InvalidDigit),
+            ("0xg", ParseDecimalError::InvalidDigit),
+            ("0x1p", ParseDecimalError::InvalidExponent),
+            ("0x1pa", ParseDecimalError::InvalidExponent),
+            ("NaN12", ParseDecimalError::InvalidDigit),
+            ("NaN0xf4240", ParseDecimalError::PayloadTooLarge),
+        ];
+        for &(s, e) in errors.iter() {
+            assert_eq!(d32::from_hex_string(s).unwrap_err(), e, "{:?}", s);
+        }
+    }
+
+    #[test]
+    fn is_infinite_combination_boundaries() {
+        let cases = [
+            (0x78000000, true),
+            (0xf8000000, true),
+            (0x7a000000, true),
+            (0x7bffffff, true),
+            (0x7c000000, false),
+            (0x7e000000, false),
+            (0xfdffffff, false),
+            (0x70000000, false),
+            (0x74000000, false),
+            (0x77ffffff, false),
+            (0x38000000, false),
+            (0x58000000, false),
+        ];
+        for &(bits, infinite) in cases.iter() {
+            let x = d32(bits);
+            assert_eq!(x.is_infinite(), infinite, "{:#x}", bits);
+            // exactly one of the three
+            let kinds = [x.is_infinite(), x.is_nan(), x.is_finite()];
+            assert_eq!(kinds.iter().filter(|&&k| k).count(), 1, "{:#x}", bits);
+        }
+    }
+
+    #[test]
+    fn signum() {
+        assert_eq!(parse("12.5").signum().0, d32::ONE.0);
+        assert_eq!(parse("-1E-101").signum().0, parse("-1").0);
+        assert_eq!(parse("inf").signum().0, d32::ONE.0);
+        assert_eq!(parse("-inf").signum().0, parse("-1").0);
+        assert_eq!(parse("-0E+7").signum().0, d32::NEG_ZERO.0);
+        assert_eq!(parse("0E-7").signum().0, d32::ZERO.0);
+        assert_eq!(parse("-NaN8").signum().to_string(), "-NaN8");
+        assert_eq!(parse("sNaN8").signum().to_string(), "NaN8");
+    }
+
+    #[test]
+    fn copy_family_keeps_signaling_nans() {
+        let snan = parse("-sNaN12");
+        for x in [
+            snan.copy(),
+            snan.negate(),
+            snan.copy_negate(),
+            snan.abs(),
+            snan.copy_abs(),
+        ] {
+            assert!(x.is_signaling());
+            assert_eq!(x.payload(), Some(12));
+        }
+        assert!(snan.copy().bit_eq(&snan));
+        assert!(!snan.negate().is_sign_minus());
+        assert!(snan.abs().negate().bit_eq(&snan));
+        assert!(snan.copy_abs().bit_eq(&snan.abs()));
+        assert!(snan.copy_negate().bit_eq(&snan.negate()));
+        assert!(d32::ONE.copy_sign(&snan).is_sign_minus());
+        assert!(snan.copy_sign(&d32::ONE).is_signaling());
+        let x = parse("1.50");
+        assert!(x.copy().bit_eq(&x));
+        assert!(x.copy_negate().bit_eq(&parse("-1.50")));
+    }
+
+    #[test]
+    fn abs_and_negate_keep_everything_else() {
+        for s in [
+            "1.50",
+            "-0E+3",
+            "-9.999999E+96",
+            "1E-101",
+            "-inf",
+            "NaN77",
+            "-sNaN5",
+        ]
+        .iter()
+        {
+            let x = parse(s);
+            assert_eq!(x.negate().negate().0, x.0, "{}", s);
+            assert_eq!(x.negate().0 ^ x.0, 0x80000000, "{}", s);
+            assert_eq!(x.abs().0, x.0 & 0x7fffffff, "{}", s);
+            assert!(!x.abs().is_sign_minus());
+            assert_eq!(x.abs().is_signaling(), x.is_signaling(), "{}", s);
+            assert_eq!(x.negate().payload(), x.payload(), "{}", s);
+            assert_eq!(x.abs().unbiased_exponent(), x.unbiased_exponent(), "{}", s);
+        }
+        // noncanonical bits survive too
+        let junk = d32(0xfe1003ff);
+        assert_eq!(junk.copy().0, 0xfe1003ff);
+        assert_eq!(junk.abs().0, 0x7e1003ff);
+        assert_eq!(junk.negate().0, 0x7e1003ff);
+    }
+
+    #[test]
+    fn sum_and_product() {
+        let values = [parse("1.5"), parse("2.25"), parse("-0.75")];
+        assert_eq!(values.iter().copied().sum::<d32>().0, parse("3.00").0);
+        assert_eq!(values.iter().sum::<d32>().0, parse("3.00").0);
+        assert_eq!(values.iter().product::<d32>().0, parse("-2.53125").0);
+        let empty: [d32; 0] = [];
+        assert_eq!(empty.iter().sum::<d32>().0, d32::ZERO.0);
+        assert_eq!(empty.iter().product::<d32>().0, d32::ONE.0);
+        // rounded at every step, not just once
+        let values = [parse("1E+7"), parse("0.5"), parse("0.5")];
+        assert_eq!(values.iter().sum::<d32>(), parse("1E+7"));
+        assert!([parse("inf"), parse("-inf")].iter().sum::<d32>().is_nan());
+    }
+
+    #[test]
+    fn sum_exact_rounds_once() {
+        let even = RoundingMode::TiesToEven;
+        // naively, 1E+7 absorbs each 0.5 on its own
+        let values = [parse("1E+7"), parse("0.5"), parse("0.5"), parse("0.5")];
+        let (x, flags) = d32::sum_exact(&values, even);
+        assert_eq!(x, parse("10000002"));
+        assert!(flags.inexact);
+        // and cancellation doesn't lose what was underneath
+        let values = [
+            parse("9999999E+90"),
+            parse("1.234567E-90"),
+            parse("-9999999E+90"),
+        ];
+        let (x, flags) = d32::sum_exact(&values, even);
+        assert_eq!(x.0, parse("1.234567E-90").0);
+        assert_eq!(flags, Flags::default());
+        let values = [parse("-1E-101"), parse("3"), parse("-5")];
+        let (x, flags) = d32::sum_exact(&values, RoundingMode::TowardZero);
+        assert_eq!(x.0, parse("-2.000000").0);
+        assert!(flags.inexact);
+        assert_eq!(
+            d32::sum_exact(&values, RoundingMode::TowardNegative).0,
+            parse("-2.000001")
+        );
+        // exact sums agree with add, cohort and all
+        let pairs = [
+            ("1.50", "2.5"),
+            ("-7E+3", "12"),
+            ("1234567", "7654321"),
+            ("-0.001", "0.001"),
+        ];
+        for &(a, b) in pairs.iter() {
+            let (a, b) = (parse(a), parse(b));
+            for &mode in [even, RoundingMode::TowardNegative].iter() {
+                let expected = a.add_with_flags(&b, mode);
+                let got = d32::sum_exact(&[a, b], mode);
+                assert_eq!((got.0).0, (expected.0).0, "{} + {}", a, b);
+                assert_eq!(got.1, expected.1, "{} + {}", a, b);
+            }
+        }
+        assert_eq!(d32::sum_exact(&[], even).0 .0, d32::ZERO.0);
+        assert_eq!(
+            d32::sum_exact(&[parse("-0"), parse("-0E-3")], even).0 .0,
+            parse("-0E-3").0
+        );
+        let (x, flags) = d32::sum_exact(&[d32::MAX, d32::MAX], even);
+        assert!(x.is_infinite() && flags.overflow);
+    }
+
+    #[test]
+    fn sum_exact_specials() {
+        let even = RoundingMode::TiesToEven;
+        let (x, flags) = d32::sum_exact(&[parse("1"), parse("inf"), parse("-inf")], even);
+        assert!(x.is_nan() && flags.invalid);
+        let (x, flags) = d32::sum_exact(&[parse("-inf"), parse("1"), parse("-inf")], even);
+        assert!(x.is_infinite() && x.is_sign_minus());
+        assert_eq!(flags, Flags::default());
+        let (x, flags) = d32::sum_exact(&[parse("inf"), parse("NaN3"), parse("sNaN4")], even);
+        assert_eq!(x.to_string(), "NaN4");
+        assert!(flags.invalid);
+        let (x, flags) = d32::sum_exact(&[parse("1"), parse("-NaN3"), parse("NaN4")], even);
+        assert_eq!(x.to_string(), "-NaN3");
+        assert_eq!(flags, Flags::default());
+    }
+
+    #[test]
+    fn mul_add_rounds_to_nearest() {
+        let (a, b, c) = (parse("1.000001"), parse("9999999"), parse("-9999999"));
+        // a single rounding keeps what a separate multiply loses
+        assert_eq!(a.mul_add(&b, &c), parse("9.999999"));
+        assert_eq!(a * b + c, parse("11"));
+        let (a, b, c) = (parse("3"), parse("0.3333335"), parse("0"));
+        assert_eq!(
+            a.mul_add(&b, &c).0,
+            a.fused_multiply_add(&b, &c, RoundingMode::TiesToEven).0
+        );
+        assert!(parse("inf").mul_add(&parse("0"), &parse("1")).is_nan());
+    }
+
+    #[test]
+    fn clamp_numeric() {
+        let (lo, hi) = (parse("-1"), parse("2.5"));
+        assert_eq!(parse("3").clamp(&lo, &hi).0, hi.0);
+        assert_eq!(parse("-7E+50").clamp(&lo, &hi).0, lo.0);
+        assert_eq!(parse("0.5").clamp(&lo, &hi).0, parse("0.5").0);
+        // equal to a bound isn't outside it
+        assert_eq!(parse("2.50000").clamp(&lo, &hi).0, parse("2.50000").0);
+        assert_eq!(parse("-0").clamp(&parse("0"), &parse("1")).0, parse("-0").0);
+        assert_eq!(parse("inf").clamp(&lo, &hi).0, hi.0);
+        assert_eq!(parse("-inf").clamp(&lo, &parse("inf")).0, lo.0);
+        assert_eq!(
+            parse("9E+96").clamp(&parse("-inf"), &parse("inf")).0,
+            parse("9E+96").0
+        );
+        assert_eq!(parse("NaN4").clamp(&lo, &hi).to_string(), "NaN4");
+        assert_eq!(parse("1").clamp(&parse("-NaN2"), &hi).to_string(), "-NaN2");
+        assert_eq!(
+            parse("NaN1").clamp(&lo, &parse("sNaN3")).to_string(),
+            "NaN3"
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn clamp_bounds_out_of_order() {
+        parse("1").clamp(&parse("2"), &parse("1"));
+    }
+
+    #[test]
+    fn every_coefficient_round_trips() {
+        // the full coefficient space is only 10M values, unlike
+        // the 4G of the whole encoding (see below); the sign
+        // alternates so both get plenty of coverage
+        for &exponent in [QMIN, -6, 0, QMAX].iter() {
+            for coefficient in 0..=MAX_COEFFICIENT as u32 {
+                let sign = coefficient % 2 == 1;
+                let x = dec(sign, coefficient as u64, exponent);
+                assert_eq!(x.coefficient(), Some(coefficient));
+                assert_eq!(x.unbiased_exponent(), Some(exponent));
+                assert_eq!(x.is_sign_minus(), sign);
+                // from_parts takes the long way round, but for
+                // values that fit it has to land on the same bits
+                if coefficient % 9973 == 0 {
+                    assert_eq!(d32::from_parts(sign, coefficient, exponent).0, x.0);
+                    assert!(x.is_canonical());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn try_from_integers() {
+        use std::convert::TryInto;
+        assert_eq!(i32::try_from(parse("-42")), Ok(-42));
+        assert_eq!(i32::try_from(parse("4.2E+1")), Ok(42));
+        assert_eq!(i32::try_from(parse("42.000")), Ok(42));
+        assert_eq!(u32::try_from(parse("-0.0")), Ok(0));
+        assert_eq!(u32::try_from(parse("4.294967E+9")), Ok(4294967000));
+        assert_eq!(
+            i64::try_from(parse("-9.223372E+18")),
+            Ok(-9223372000000000000)
+        );
+        assert_eq!(u64::try_from(parse("1.8E+19")), Ok(18000000000000000000));
+        let x: Result<i64, _> = parse("123").try_into();
+        assert_eq!(x, Ok(123));
+        // out of range is not the same as not an integer
+        assert_eq!(
+            i32::try_from(parse("2.5")),
+            Err(ConversionError::NotInteger)
+        );
+        assert_eq!(
+            u64::try_from(parse("-1E-101")),
+            Err(ConversionError::NotInteger)
+        );
+        assert_eq!(
+            i32::try_from(parse("3E+9")),
+            Err(ConversionError::OutOfRange)
+        );
+        assert_eq!(u32::try_from(parse("-1")), Err(ConversionError::OutOfRange));
+        assert_eq!(
+            u64::try_from(parse("2E+19")),
+            Err(ConversionError::OutOfRange)
+        );
+        assert_eq!(
+            i64::try_from(parse("9.999999E+96")),
+            Err(ConversionError::OutOfRange)
+        );
+        assert_eq!(i64::try_from(parse("-inf")), Err(ConversionError::Infinite));
+        assert_eq!(u32::try_from(parse("sNaN")), Err(ConversionError::NaN));
+        assert_eq!(i32::try_from(parse("-NaN2")), Err(ConversionError::NaN));
+    }
+
+    #[test]
+    fn into_floats() {
+        assert_eq!(f64::from(parse("0.1")), 0.1);
+        assert_eq!(f32::from(parse("0.1")), 0.1f32);
+        assert_eq!(f64::from(parse("-1.234567E-50")), -1.234567e-50);
+        let zero: f64 = parse("-0E+7").into();
+        assert!(zero == 0.0 && zero.is_sign_negative());
+        let zero: f32 = parse("0E-50").into();
+        assert!(zero == 0.0 && zero.is_sign_positive());
+        assert_eq!(f64::from(parse("-inf")), f64::NEG_INFINITY);
+        assert_eq!(f32::from(parse("9.999999E+96")), f32::INFINITY);
+        assert!(f64::from(parse("NaN")).is_nan());
+        assert!(f32::from(parse("-sNaN3")).is_sign_negative());
+        fn takes_f64<T: Into<f64>>(x: T) -> f64 {
+            x.into()
+        }
+        assert_eq!(takes_f64(parse("2.5")), 2.5);
+    }
+
+    #[test]
+    fn classify_slice_matches_class() {
+        // a stride through every bit pattern, plus the
+        // subnormal boundary on both sides of zero
+        let mut xs: Vec<d32> = (0..=u32::MAX).step_by(4099).map(d32).collect();
+        for s in [
+            "1E-101",
+            "9.99999E-96",
+            "1E-95",
+            "1.000000E-95",
+            "0E-101",
+            "0E+90",
+        ] {
+            xs.push(parse(s));
+            xs.push(parse(s).negate());
+        }
+        // second form, and a noncanonical declet
+        xs.push(d32(0x6c000000));
+        xs.push(d32(0x225003ff));
+        let mut out = vec![Class::QuietNaN; xs.len()];
+        d32::classify_slice(&xs, &mut out);
+        for (x, class) in xs.iter().zip(&out) {
+            assert_eq!(*class, x.class(), "{:08x}", x.to_bits());
+        }
+        let mask = d32::is_nan_mask(&xs);
+        assert!(xs.iter().zip(&mask).all(|(x, &nan)| nan == x.is_nan()));
+        assert_eq!(d32::is_nan_mask(&[]), Vec::<bool>::new());
+    }
+
+    #[test]
+    #[should_panic]
+    fn classify_slice_lengths_must_match() {
+        d32::classify_slice(&[d32::ONE, d32::NAN], &mut [Class::QuietNaN]);
+    }
+
+    #[test]
+    fn class_number() {
+        let values = [
+            "sNaN",
+            "-NaN",
+            "-inf",
+            "-1",
+            "-1E-101",
+            "-0",
+            "0E+7",
+            "9.99999E-96",
+            "1E-95",
+            "inf",
+        ];
+        for (i, s) in values.iter().enumerate() {
+            assert_eq!(parse(s).class_number(), i as i32, "{}", s);
+        }
+    }
+
+    #[test]
+    fn abs_sub() {
+        let even = RoundingMode::TiesToEven;
+        assert_eq!(parse("2").abs_sub(&parse("5.5"), even).0, parse("3.5").0);
+        assert_eq!(parse("5.5").abs_sub(&parse("2"), even).0, parse("3.5").0);
+        assert_eq!(parse("-3").abs_sub(&parse("4"), even).0, parse("7").0);
+        assert!(!parse("1")
+            .abs_sub(&parse("1.0"), RoundingMode::TowardNegative)
+            .is_sign_minus());
+        // rounded once, as a positive number, in either order
+        let (a, b) = (parse("1"), parse("3.333333E-7"));
+        for &(x, y) in [(a, b), (b, a)].iter() {
+            let (d, flags) = x.abs_sub_with_flags(&y, RoundingMode::TowardPositive);
+            assert_eq!(d.0, parse("0.9999997").0);
+            assert!(flags.inexact);
+            let d = x.abs_sub(&y, RoundingMode::TowardZero);
+            assert_eq!(d.0, parse("0.9999996").0);
+        }
+        assert!(parse("-inf").abs_sub(&parse("1"), even).is_infinite());
+        assert!(
+            parse("inf")
+                .abs_sub_with_flags(&parse("inf"), even)
+                .1
+                .invalid
+        );
+        assert_eq!(
+            parse("1").abs_sub(&parse("-NaN2"), even).to_string(),
+            "-NaN2"
+        );
+    }
+
+    #[test]
+    fn saturating_add() {
+        let even = RoundingMode::TiesToEven;
+        let (x, flags) = d32::MAX.saturating_add_with_flags(&d32::MAX, even);
+        assert_eq!(x.0, d32::MAX.0);
+        assert!(flags.overflow && flags.inexact);
+        let (x, flags) = d32::MAX
+            .negate()
+            .saturating_add_with_flags(&parse("-5E+90"), even);
+        assert_eq!(x.0, d32::MAX.negate().0);
+        assert!(flags.overflow);
+        assert_eq!(parse("1").saturating_add(&parse("2"), even), parse("3"));
+        assert!(parse("-inf")
+            .saturating_add(&parse("1"), even)
+            .is_infinite());
+        assert!(parse("inf").saturating_add(&parse("-inf"), even).is_nan());
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary_values() {
+        use arbitrary::{Arbitrary, Unstructured};
+        let bytes: Vec<u8> = (0..=255u8).cycle().take(5000).collect();
+        let mut u = Unstructured::new(&bytes);
+        let mut canonical = 0;
+        for _ in 0..1000 {
+            let x = d32::arbitrary(&mut u).unwrap();
+            canonical += x.is_canonical() as u32;
+        }
+        assert!(canonical >= 500);
+    }
+
+    #[test]
+    fn dec32_literals() {
+        assert!(dec32!(1.250).bit_eq(&parse("1.250")));
+        assert!(dec32!(-3.14E-2).bit_eq(&parse("-3.14E-2")));
+        assert!(dec32!(+7).bit_eq(&parse("7")));
+        assert!(dec32!(100).bit_eq(&parse("100")));
+        assert!(dec32!(0.0).bit_eq(&parse("0.0")));
+        assert!(dec32!(-0).bit_eq(&d32::NEG_ZERO));
+        assert!(dec32!(9.999999e96).bit_eq(&d32::MAX));
+        assert!(dec32!(1e-101).bit_eq(&parse("1E-101")));
+        assert!(dec32!(0.000_001_5).bit_eq(&parse("0.0000015")));
+        assert!(dec32!(00001234.567e-7).bit_eq(&parse("1234.567E-7")));
+        // folded into range without changing the value
+        assert!(dec32!(1E96).bit_eq(&parse("1E96")));
+        assert!(dec32!(100E-103).bit_eq(&parse("1E-101")));
+        const RATES: [d32; 2] = [dec32!(0.0825), dec32!(0.05)];
+        assert!(RATES[0].bit_eq(&d32::from_raw_parts(false, 825, 97)));
+        assert!(RATES[1].bit_eq(&parse("0.05")));
+    }
+
+    #[test]
+    fn from_literal_rejects() {
+        for s in [
+            "12345678",
+            "1.234567E100",
+            "1E-102",
+            "abc",
+            "",
+            "-",
+            "1e",
+            "1.5f32",
+            "1..2",
+        ] {
+            let literal = s.to_string();
+            let result = std::panic::catch_unwind(|| d32::from_literal(&literal));
+            assert!(result.is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn from_raw_parts_in_const() {
+        const TAX_RATE: d32 = d32::from_raw_parts(false, 825, 97);
+        assert!(TAX_RATE.bit_eq(&parse("0.0825")));
+        const TABLE: [d32; 3] = [
+            d32::from_raw_parts(false, 1, 101),
+            d32::from_raw_parts(true, 9999999, 191),
+            d32::from_raw_parts(false, 8000000, 0),
+        ];
+        assert!(TABLE[0].bit_eq(&d32::ONE));
+        assert!(TABLE[1].bit_eq(&d32::MAX.negate()));
+        assert!(TABLE[2].bit_eq(&parse("8.000000E-95")));
+        assert!(d32::from_raw_parts(true, 0, 50).bit_eq(&parse("-0E-51")));
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_raw_parts_checks_coefficient() {
+        d32::from_raw_parts(false, 10000000, 101);
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_raw_parts_checks_exponent() {
+        d32::from_raw_parts(false, 1, 192);
+    }
+
+    #[test]
+    fn byte_order() {
+        assert_eq!(d32::MAX.to_be_bytes(), [0x77, 0xf3, 0xfc, 0xff]);
+        assert_eq!(d32::MAX.to_le_bytes(), [0xff, 0xfc, 0xf3, 0x77]);
+        let x = parse("-7.50");
+        assert_eq!(x.to_be_bytes(), x.to_bits().to_be_bytes());
+        assert_eq!(x.to_be_bytes()[0] & 0x80, 0x80);
+        assert!(d32::from_be_bytes(x.to_be_bytes()).bit_eq(&x));
+        assert!(d32::from_le_bytes(x.to_le_bytes()).bit_eq(&x));
+        assert!(d32::from_be_bytes([0x7e, 0, 0, 5]).is_signaling());
+        const ONE: d32 = d32::from_be_bytes([0x22, 0x50, 0x00, 0x01]);
+        assert!(ONE.bit_eq(&d32::ONE));
+    }
+
+    #[test]
+    fn layout_is_a_u32() {
+        assert_eq!(std::mem::size_of::<d32>(), 4);
+        assert_eq!(std::mem::align_of::<d32>(), std::mem::align_of::<u32>());
+        assert_eq!(std::mem::size_of::<[d32; 3]>(), 12);
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn bytemuck_casts() {
+        let values = [d32::ONE, d32::NAN, d32::MAX.negate()];
+        let bits: &[u32] = bytemuck::cast_slice(&values);
+        assert_eq!(bits, &[0x22500001, 0x7c000000, 0xf7f3fcff]);
+        let bytes: &[u8] = bytemuck::cast_slice(&values);
+        assert_eq!(bytes.len(), 12);
+        let back: &[d32] = bytemuck::cast_slice(bits);
+        assert!(back.iter().zip(&values).all(|(x, y)| x.bit_eq(y)));
+        let zero: d32 = bytemuck::Zeroable::zeroed();
+        assert!(zero.is_zero() && !zero.is_sign_minus());
+        assert_eq!(zero.quantum_exponent(), Some(-101));
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn rust_decimal_interchange() {
+        use rust_decimal::Decimal;
+        // the scale comes across as the exponent
+        let x = d32::from(Decimal::new(-12500, 3));
+        assert_eq!(parts(x), (true, 12500, -3));
+        let third = Decimal::ONE / Decimal::from(3);
+        assert_eq!(parts(d32::from(third)), (false, 3333333, -7));
+        assert_eq!(parts(d32::from(Decimal::MAX)), (false, 7922816, 22));
+        let mut zero = Decimal::ZERO;
+        zero.set_sign_negative(true);
+        assert!(d32::from(zero).is_sign_minus());
+        // and back, exactly
+        let back = Decimal::try_from(parse("-12.500")).unwrap();
+        assert_eq!((back.mantissa(), back.scale()), (-12500, 3));
+        let back = Decimal::try_from(parse("1.5E20")).unwrap();
+        assert_eq!((back.mantissa(), back.scale()), (150000000000000000000, 0));
+        // trailing zeros only go as far as the scale needs
+        let back = Decimal::try_from(parse("1.200000E-23")).unwrap();
+        assert_eq!((back.mantissa(), back.scale()), (120000, 28));
+        let back = Decimal::try_from(parse("-0E-101")).unwrap();
+        assert!(back.is_zero() && back.is_sign_negative());
+        for (s, err) in [
+            ("1E29", ConversionError::OutOfRange),
+            ("1.234567E-25", ConversionError::OutOfRange),
+            ("-Inf", ConversionError::Infinite),
+            ("NaN", ConversionError::NaN),
+        ] {
+            assert_eq!(Decimal::try_from(parse(s)), Err(err));
+        }
+    }
+
+    #[cfg(feature = "ffi")]
+    #[test]
+    fn ffi_wrappers() {
+        use super::ffi::*;
+        assert_eq!(std::mem::size_of::<d32>(), 4);
+        assert_eq!(std::mem::align_of::<d32>(), 4);
+        let one = d32_from_bits(0x22500001);
+        assert_eq!(d32_to_bits(one), 0x22500001);
+        let third = d32_div(one, d32_from_i32(3), 0);
+        assert_eq!(d32_to_bits(third), parse("0.3333333").to_bits());
+        let third = d32_div(one, d32_from_i32(3), 3);
+        assert_eq!(d32_to_bits(third), parse("0.3333334").to_bits());
+        // unknown modes round to nearest even
+        let third = d32_div(one, d32_from_i32(3), 99);
+        assert_eq!(d32_to_bits(third), parse("0.3333333").to_bits());
+        let x = d32_add(d32_from_i32(2), d32_sub(one, d32_from_i32(4), 0), 0);
+        assert_eq!(d32_to_bits(x), parse("-1").to_bits());
+        let x = d32_fma(d32_from_i32(2), d32_from_i32(3), one, 0);
+        assert_eq!(d32_to_f64(x), 7.0);
+        assert_eq!(
+            d32_to_bits(d32_sqrt(d32_from_i32(4), 0)),
+            parse("2").to_bits()
+        );
+        let x = d32_quantize(d32_from_f64(1.25, 0), parse("0.1"), 2);
+        assert_eq!(d32_to_bits(x), parse("1.2").to_bits());
+        assert_eq!(d32_to_bits(d32_mul(one, d32::NAN, 0)), 0x7c000000);
+        assert_eq!(d32_compare(one, d32_from_i32(2)), -1);
+        assert_eq!(d32_compare(one, parse("1.00")), 0);
+        assert_eq!(d32_compare(d32_from_i32(2), one), 1);
+        assert_eq!(d32_compare(one, d32::NAN), 2);
+        assert!(d32_total_order(parse("-0"), parse("0")));
+        assert_eq!(d32_class(parse("-inf")), 2);
+        assert_eq!(d32_class(parse("1E-101")), 7);
+    }
+
+    #[cfg(feature = "proptest")]
+    mod properties {
+        use super::super::*;
+        use proptest::prelude::*;
+
+        proptest! {
+            #[test]
+            fn total_order_is_total(x in strategy::edge_cases(), y in strategy::edge_cases()) {
+                prop_assert!(x.total_order(&y) || y.total_order(&x));
+            }
+
+            #[test]
+            fn canonicalize_is_canonical(x in strategy::any_bits()) {
+                prop_assert!(x.canonicalize().is_canonical());
+            }
+
+            #[test]
+            fn finite_round_trips_through_strings(x in strategy::finite()) {
+                let y: d32 = x.to_string().parse().unwrap();
+                prop_assert_eq!(x.to_bits(), y.to_bits());
+            }
+        }
+    }
+
+    /*  don't actually run this test
+        i did it merely out of paranoia, this having been my first test of the library
+        exhaustive testing on 32 bits is, to say the least, impractical, and to say the most,
+        fucking nonsense
+
+        use std::time::Instant;
+
+        #[test]
+        fn total_order_test() {
+            let now = Instant::now();
+
+            for i in 0..=u32::MAX {
+                assert!(d32(i).total_order(&d32(i)));
+            }
+
+            let elapsed = now.elapsed();
+
+            println!("Time: {} micros", elapsed.as_micros());
+            println!("Time per op: {} micros", elapsed.as_micros() as f64 / u32::MAX as f64);
+        }
+    */
+}